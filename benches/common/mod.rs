@@ -0,0 +1,15 @@
+//! Helpers shared by the benchmarks.
+
+use std::time::{Duration, Instant};
+
+/// The shortest of `iterations` runs of `f`, with the result of that run.
+pub fn fastest<T>(iterations: usize, mut f: impl FnMut() -> T) -> (Duration, T) {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            (start.elapsed(), result)
+        })
+        .min_by_key(|(elapsed, _)| *elapsed)
+        .unwrap()
+}
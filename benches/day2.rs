@@ -0,0 +1,95 @@
+//! Parses a large generated game log with day 2, and compares it with the original parser, which
+//! compiled its regex for every set. Run with `cargo bench --bench day2`.
+
+mod common;
+
+use std::time::Duration;
+
+use advent_of_rust_2023::{
+    days::day2::{self, CubeSet, Day2},
+    Solution,
+};
+use common::fastest;
+
+const GAMES: usize = 200_000;
+const ITERATIONS: usize = 5;
+
+/// The original parser, with its unused game ids dropped.
+mod original {
+    use std::str::FromStr;
+
+    #[derive(Default)]
+    pub struct CubeSet {
+        pub blues: i32,
+        pub reds: i32,
+        pub greens: i32,
+    }
+
+    impl FromStr for CubeSet {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let re = regex::Regex::new(r"(\d+) (r|g|b)").unwrap();
+            let mut set = CubeSet::default();
+
+            for cap in re.captures_iter(s) {
+                let num = cap[1].parse::<i32>().unwrap();
+                match &cap[2] {
+                    "r" => set.reds = num,
+                    "g" => set.greens = num,
+                    "b" => set.blues = num,
+                    _ => panic!("Invalid input"),
+                }
+            }
+
+            Ok(set)
+        }
+    }
+
+    pub fn read_input(log: &str) -> Vec<Vec<CubeSet>> {
+        log.lines()
+            .map(|l| {
+                let (_, sets) = l.split_once(": ").unwrap();
+                sets.split("; ")
+                    .map(|s| s.parse::<CubeSet>().unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+fn report(name: &str, log: &str, duration: Duration) {
+    let megabytes = log.len() as f64 / 1e6;
+    println!(
+        "{name:<32} {duration:>12.2?} {:>8.1} MB/s",
+        megabytes / duration.as_secs_f64()
+    );
+}
+
+fn main() {
+    let bag = CubeSet::puzzle_bag();
+    let log = day2::generate(GAMES, &bag, 2023);
+
+    let (parser, games) = fastest(ITERATIONS, || Day2::parse(log.as_bytes()).unwrap());
+    // the original parser takes a while on every set, so it runs once
+    let (regex, original) = fastest(1, || original::read_input(&log));
+
+    let cubes = games
+        .iter()
+        .flat_map(|g| &g.sets)
+        .flat_map(|s| s.iter())
+        .map(|(_, n)| u64::from(n));
+    let original_cubes = original
+        .iter()
+        .flatten()
+        .map(|s| u64::try_from(s.reds + s.greens + s.blues).unwrap());
+    assert_eq!(original_cubes.sum::<u64>(), cubes.sum::<u64>());
+
+    report(&format!("single pass, {GAMES} games"), &log, parser);
+    report(&format!("regex per set, {GAMES} games"), &log, regex);
+    println!(
+        "{:<32} {:>11.0}x",
+        "speedup",
+        regex.as_secs_f64() / parser.as_secs_f64()
+    );
+}
@@ -0,0 +1,135 @@
+//! Parses and solves large generated schematics with the day 3 span index, and compares every
+//! phase with the original solution, which rebuilt the strings of the numbers next to each symbol
+//! from its row. Run with `cargo bench --bench day3`.
+
+mod common;
+
+use advent_of_rust_2023::{
+    days::day3::{self, Day3},
+    Solution,
+};
+use common::fastest;
+
+/// Width, height and the most digits of the numbers of the generated schematics. The puzzle's is
+/// 140 by 140 with up to 3 digits, the original solution is quadratic in the width.
+const SCHEMATICS: [(usize, usize, usize); 4] = [
+    (140, 140, 3),
+    (1_000, 1_000, 3),
+    (1_000, 1_000, 9),
+    (4_000, 250, 3),
+];
+const ITERATIONS: usize = 3;
+
+/// The original solution, with the sums widened and checked so that it agrees on large schematics.
+mod original {
+    pub fn read_input(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    pub fn part1(input: &[String]) -> u64 {
+        input.iter().enumerate().fold(0, |acc, (y, line)| {
+            acc + line
+                .chars()
+                .enumerate()
+                .filter(|c| c.1 != '.' && !c.1.is_ascii_digit())
+                .fold(0, |acc, (x, _)| {
+                    acc + get_neighbour_numbers(x, y, input).iter().sum::<u64>()
+                })
+        })
+    }
+
+    /// `None` if the sum of the gear ratios does not fit into a `u64`.
+    pub fn part2(input: &[String]) -> Option<u64> {
+        input.iter().enumerate().try_fold(0, |acc: u64, (y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|c| c.1 == '*')
+                .try_fold(acc, |acc, (x, _)| {
+                    let neighbours = get_neighbour_numbers(x, y, input);
+                    acc.checked_add(if neighbours.len() == 2 {
+                        neighbours[0].checked_mul(neighbours[1])?
+                    } else {
+                        0
+                    })
+                })
+        })
+    }
+
+    fn get_neighbour_numbers(x: usize, y: usize, input: &[String]) -> Vec<u64> {
+        let mut neighbours = Vec::new();
+
+        let get_left = |y: usize, x: usize| {
+            let first_digit_offset = input[y]
+                .chars()
+                .rev()
+                .skip(input[y].len() - x)
+                .position(|c| !c.is_ascii_digit())
+                .unwrap_or(x);
+
+            input[y]
+                .chars()
+                .skip(x - first_digit_offset)
+                .take_while(|&c| c.is_ascii_digit())
+                .collect::<String>()
+        };
+
+        let get_right = |y: usize, x: usize| {
+            input[y]
+                .chars()
+                .skip(x + 1)
+                .take_while(|&c| c.is_ascii_digit())
+                .collect::<String>()
+        };
+
+        neighbours.push(get_left(y, x));
+        neighbours.push(get_right(y, x));
+
+        if y > 0 && input[y - 1].chars().nth(x).unwrap().is_ascii_digit() {
+            neighbours.push(get_left(y - 1, x));
+        } else if y > 0 {
+            neighbours.push(get_left(y - 1, x));
+            neighbours.push(get_right(y - 1, x));
+        }
+        if y < input.len() - 1 && input[y + 1].chars().nth(x).unwrap().is_ascii_digit() {
+            neighbours.push(get_left(y + 1, x));
+        } else if y < input.len() - 1 {
+            neighbours.push(get_left(y + 1, x));
+            neighbours.push(get_right(y + 1, x));
+        }
+
+        neighbours.iter().filter_map(|n| n.parse().ok()).collect()
+    }
+}
+
+fn main() {
+    println!(
+        "{:>6} {:>6} {:>6}  {:<6} {:>12} {:>12} {:>9}",
+        "width", "height", "digits", "phase", "indexed", "original", "speedup"
+    );
+    for (width, height, digits) in SCHEMATICS {
+        let text = day3::generate(width, height, digits, 2023);
+
+        let (parse, graph) = fastest(ITERATIONS, || Day3::parse(text.as_bytes()).unwrap());
+        let (part1, answer1) = fastest(ITERATIONS, || Day3::part1(&graph).unwrap());
+        let (part2, answer2) = fastest(ITERATIONS, || Day3::part2(&graph).ok());
+
+        let (original_parse, input) = fastest(ITERATIONS, || original::read_input(&text));
+        let (original_part1, expected1) = fastest(ITERATIONS, || original::part1(&input));
+        let (original_part2, expected2) = fastest(ITERATIONS, || original::part2(&input));
+        assert_eq!((expected1, expected2), (answer1, answer2));
+
+        let total = parse + part1 + part2;
+        let original_total = original_parse + original_part1 + original_part2;
+        for (phase, new, old) in [
+            ("parse", parse, original_parse),
+            ("part 1", part1, original_part1),
+            ("part 2", part2, original_part2),
+            ("total", total, original_total),
+        ] {
+            println!(
+                "{width:>6} {height:>6} {digits:>6}  {phase:<6} {new:>12.2?} {old:>12.2?} {:>8.2}x",
+                old.as_secs_f64() / new.as_secs_f64()
+            );
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    fmt::{self, Display},
+    fs::{self, File},
+    io::BufReader,
+    path::Path,
+};
+
+use itertools::Itertools;
+
+use crate::{days, inputs, Part, Run};
+
+/// Registry of verified answers, relative to the repository root.
+pub const PATH: &str = "answers.txt";
+
+/// Registered answer of one part of a day, for one of its input files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    /// File name inside the day's input directory.
+    pub input: String,
+    pub part: Part,
+    /// `None` until the answer is known, written as `?` in the registry.
+    pub answer: Option<String>,
+    /// Too slow to check on every test run.
+    pub slow: bool,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}, {}, part {}", self.day, self.input, self.part)
+    }
+}
+
+/// Parses the registry: one whitespace-separated `day input part answer [slow]` entry per line.
+/// Blank lines and lines starting with `#` are ignored.
+pub fn parse(text: &str) -> Result<Vec<Answer>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            let error = |e: String| format!("{PATH}:{}: {e}", i + 1);
+            let fields = line.split_whitespace().collect_vec();
+            let (day, input, part, answer, flags) = match fields.as_slice() {
+                [day, input, part, answer, flags @ ..] => (day, input, part, answer, flags),
+                _ => return Err(error("expected `day input part answer [slow]`".to_string())),
+            };
+
+            Ok(Answer {
+                day: day
+                    .parse()
+                    .map_err(|_| error(format!("invalid day `{day}`")))?,
+                input: input.to_string(),
+                part: part.parse().map_err(error)?,
+                answer: Some(answer.to_string()).filter(|answer| answer != "?"),
+                slow: match flags {
+                    [] => false,
+                    ["slow"] => true,
+                    _ => return Err(error(format!("unknown flags `{}`", flags.join(" ")))),
+                },
+            })
+        })
+        .collect()
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Answer>, String> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    parse(&text)
+}
+
+/// An answer that could not be reproduced.
+#[derive(Debug)]
+pub struct Failure<'a> {
+    pub answer: &'a Answer,
+    pub reason: String,
+}
+
+impl Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.answer, self.reason)
+    }
+}
+
+/// Solves every known answer, parsing each input file only once.
+pub fn verify(answers: &[Answer]) -> Vec<Failure<'_>> {
+    answers
+        .iter()
+        .filter(|answer| answer.answer.is_some())
+        .into_group_map_by(|answer| (answer.day, answer.input.as_str()))
+        .into_iter()
+        .sorted_by_key(|(key, _)| *key)
+        .flat_map(|((day, input), answers)| {
+            let parts = answers.iter().map(|answer| answer.part).collect_vec();
+            let solved = solve(day, input, &parts);
+
+            answers.into_iter().filter_map(move |answer| {
+                let reason = match &solved {
+                    Ok(solved) => {
+                        let solved = solved.parts.iter().find(|s| s.part == answer.part)?;
+                        let expected = answer.answer.as_ref()?;
+                        match &solved.answer {
+                            Ok(answer) if answer == expected => return None,
+                            Ok(answer) => format!("expected {expected}, got {answer}"),
+                            Err(e) => e.to_string(),
+                        }
+                    }
+                    Err(e) => e.clone(),
+                };
+                Some(Failure { answer, reason })
+            })
+        })
+        .collect()
+}
+
+fn solve(day: u8, input: &str, parts: &[Part]) -> Result<Run, String> {
+    let solution = days::get(day).ok_or(format!("day {day} is not registered"))?;
+    let path = inputs::dir(day).join(input);
+    let file = File::open(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+    (solution.run)(&mut BufReader::new(file), parts).map_err(|e| e.to_string())
+}
@@ -0,0 +1,232 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use itertools::Itertools;
+
+use crate::{days::Day, Part};
+
+/// Benchmark results, relative to the repository root.
+pub const PATH: &str = "benchmarks.txt";
+
+/// Separately timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            _ => s
+                .strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Phase::Part)
+                .ok_or(format!("invalid phase `{s}`")),
+        }
+    }
+}
+
+/// Summary of repeated measurements of one phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: Phase,
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    fn new(day: u8, phase: Phase, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timing {
+            day,
+            phase,
+            iterations: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Solves `input` with `day` the given number of times, timing parsing and each part separately.
+/// Fails if the input does not parse or a part has no answer.
+pub fn measure(
+    day: &Day,
+    input: &[u8],
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Timing>, String> {
+    assert!(iterations > 0, "at least one iteration is needed");
+
+    let mut samples = vec![Vec::with_capacity(iterations); parts.len() + 1];
+    for _ in 0..iterations {
+        let mut reader = input;
+        let run = (day.run)(&mut reader, parts).map_err(|e| e.to_string())?;
+        samples[0].push(run.parse);
+        for (i, solved) in run.parts.iter().enumerate() {
+            solved.answer.as_ref().map_err(|e| e.to_string())?;
+            samples[i + 1].push(solved.duration);
+        }
+    }
+
+    let phases = std::iter::once(Phase::Parse).chain(parts.iter().map(|&part| Phase::Part(part)));
+    Ok(phases
+        .zip(samples)
+        .map(|(phase, samples)| Timing::new(day.number, phase, samples))
+        .collect())
+}
+
+/// Renders timings in the format read by [`parse`], in nanoseconds.
+pub fn to_text(timings: &[Timing]) -> String {
+    let mut text = String::from("# day  phase  iterations  min_ns  median_ns  max_ns\n");
+    for t in timings.iter().sorted_by_key(|t| (t.day, t.phase)) {
+        text += &format!(
+            "{} {} {} {} {} {}\n",
+            t.day,
+            t.phase,
+            t.iterations,
+            t.min.as_nanos(),
+            t.median.as_nanos(),
+            t.max.as_nanos()
+        );
+    }
+    text
+}
+
+pub fn parse(text: &str) -> Result<Vec<Timing>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            let error = |e: String| format!("{PATH}:{}: {e}", i + 1);
+            let fields = line.split_whitespace().collect_vec();
+            let [day, phase, iterations, min, median, max] = fields.as_slice() else {
+                return Err(error(
+                    "expected `day phase iterations min_ns median_ns max_ns`".to_string(),
+                ));
+            };
+            let nanos = |s: &str| {
+                s.parse()
+                    .map(Duration::from_nanos)
+                    .map_err(|_| error(format!("invalid duration `{s}`")))
+            };
+
+            Ok(Timing {
+                day: day
+                    .parse()
+                    .map_err(|_| error(format!("invalid day `{day}`")))?,
+                phase: phase.parse().map_err(error)?,
+                iterations: iterations
+                    .parse()
+                    .map_err(|_| error(format!("invalid iterations `{iterations}`")))?,
+                min: nanos(min)?,
+                median: nanos(median)?,
+                max: nanos(max)?,
+            })
+        })
+        .collect()
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Timing>, String> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    parse(&text)
+}
+
+/// Like [`load`], without timings if there is no file yet.
+pub fn load_existing(path: impl AsRef<Path>) -> Result<Vec<Timing>, String> {
+    if path.as_ref().exists() {
+        load(path)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+/// Replaces the timings of `baseline` with the ones of `current`, keeping phases not measured again.
+pub fn merge(baseline: &[Timing], current: &[Timing]) -> Vec<Timing> {
+    baseline
+        .iter()
+        .filter(|old| {
+            !current
+                .iter()
+                .any(|new| (new.day, new.phase) == (old.day, old.phase))
+        })
+        .chain(current)
+        .cloned()
+        .collect()
+}
+
+/// Relative change of the median of `current` against `baseline`, if the phase was measured before.
+pub fn change(baseline: &[Timing], current: &Timing) -> Option<f64> {
+    baseline
+        .iter()
+        .find(|old| (old.day, old.phase) == (current.day, current.phase))
+        .filter(|old| !old.median.is_zero())
+        .map(|old| current.median.as_secs_f64() / old.median.as_secs_f64() - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let timing = Timing::new(
+            5,
+            Phase::Part(Part::Two),
+            vec![
+                Duration::from_millis(3),
+                Duration::from_millis(1),
+                Duration::from_millis(2),
+            ],
+        );
+        assert_eq!(Duration::from_millis(1), timing.min);
+        assert_eq!(Duration::from_millis(2), timing.median);
+        assert_eq!(Duration::from_millis(3), timing.max);
+
+        let timings = vec![timing];
+        assert_eq!(timings, parse(&to_text(&timings)).unwrap());
+    }
+
+    #[test]
+    fn test_change() {
+        let timing = |day, median| Timing {
+            day,
+            phase: Phase::Parse,
+            iterations: 1,
+            min: Duration::from_millis(median),
+            median: Duration::from_millis(median),
+            max: Duration::from_millis(median),
+        };
+        let baseline = [timing(1, 100), timing(2, 100)];
+        let current = [timing(1, 150)];
+
+        assert!((change(&baseline, &current[0]).unwrap() - 0.5).abs() < 1e-9);
+        assert_eq!(None, change(&current, &baseline[1]));
+        assert_eq!(
+            vec![timing(2, 100), timing(1, 150)],
+            merge(&baseline, &current)
+        );
+    }
+}
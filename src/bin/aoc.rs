@@ -0,0 +1,860 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fs,
+    io::{self, Read},
+    iter::Peekable,
+    path::{Path, PathBuf},
+    process,
+};
+
+use advent_of_rust_2023::{
+    answers, bench,
+    days::{self, day1, day2, day3},
+    inputs, readme, scaffold, Part, Solution,
+};
+use itertools::Itertools;
+use serde_json::json;
+
+const USAGE: &str = "\
+Usage: aoc run <DAY> [INPUT | --example [N]] [--part 1|2] [--format text|json]
+       aoc bench [DAY...] [--part 1|2] [--iterations N] [--slow]
+                 [--baseline FILE] [--output FILE] [--threshold PERCENT]
+       aoc calibrate [INPUT | --example [N]] [--vocabulary NAME|FILE] [--extended]
+                     [--missing error|skip|zero] [--stream [--parallel]]
+                     [--explain [--format text|json]]
+       aoc cubes [INPUT | --example [N]] [--bag SET | --bag-file FILE]
+                 [--report [--format text|json] | --estimate [--limit N]
+                  | --query QUERY [--format text|json] | --export text|csv|json]
+                 [--import text|csv|json]
+       aoc gears [INPUT | --example [N]] [--rules RULES]
+       aoc new <DAY>
+       aoc readme [--check]
+
+run:
+  INPUT defaults to inputs/dayN/input.txt, `-` reads it from stdin.
+  --example N picks inputs/dayN/exampleN.txt, N defaults to 1.
+  --format json prints one JSON object per part, with the answer as a string,
+  its duration in nanoseconds and the FNV-1a hash of the input.
+  A part without an answer for the input reports why instead, after which the
+  other parts still run.
+
+bench:
+  Times parsing and every part with a verified answer for inputs/dayN/input.txt,
+  over all days by default. Parts flagged `slow` in answers.txt need --slow.
+  Results are merged into --output (benchmarks.txt) and compared against
+  --baseline (the previous content of --output); medians more than
+  --threshold percent (10) slower are reported as regressions. A debug build
+  only writes to an --output other than benchmarks.txt.
+
+calibrate:
+  Sums the day 1 calibration values, with the number words of --vocabulary:
+  digits (none, as in part 1), english (the default, as in part 2), polish,
+  german, or a file with a word and its number per line. --extended also
+  recognises the English numbers up to 99, like `seventeen` or `twenty-one`: a
+  first word counts with its leading digit and a last word with its units digit.
+  --missing decides what happens to lines without digits: fail with the line
+  number (the default), skip them or count them as 0. How many lines were
+  affected is reported on stderr, listing the first 20 of them.
+  --stream reads the input in fixed-size blocks instead of loading it whole,
+  --parallel also calibrates each block on all cores.
+  --explain lists the first and last token of every line with their byte
+  offsets instead, as a table or as one JSON object per line.
+
+cubes:
+  Solves day 2 with the bag given as a draw, like `--bag '12 red, 13 green,
+  14 blue'` (the puzzle's bag, the default), or read from a file with one or
+  more such lines. Draws of colours that are not in the bag are rejected.
+  --report lists every draw that exceeds the bag, with its game, set (from 1),
+  colour, count and the bag's limit, then the minimal bag of every game and its
+  power, as tables or as one JSON object per game.
+  --estimate prints the most likely bag behind the log instead, with 95%
+  confidence bounds per colour, taking every set as drawn without replacement
+  from the full bag. Bags hold at most --limit cubes (1000, up to 1048576).
+  --query filters the games, like 'any draw has red > 10 and blue < 3' or
+  'minimal bag power > 500', and prints their ids, count, sum of ids and sum of
+  powers. Games have the fields id, sets, power and minimal COLOUR; inside
+  'any draw has ...' or 'all draws have ...', COLOUR and cubes count the cubes
+  of a draw. Conditions combine with and, or, not and parentheses, numbers with
+  + - * /.
+  --export prints the games in the puzzle's syntax, as CSV with one row per
+  draw or as JSON, and --import reads them in any of these formats.
+
+gears:
+  Solves day 3 with other --rules, like 'gears=*# arity=2+ combine=sum'. The
+  settings symbols and gears take any or the characters themselves, arity a
+  number of numbers with a + for at least that many, and combine one of
+  product, sum and max. Settings not given keep the puzzle's: every character
+  but . is a symbol, and a * touching exactly two numbers is a gear whose ratio
+  is their product. Gears touching more numbers than arity allows are reported.
+
+new:
+  Creates src/days/dayN.rs and inputs/dayN/, and registers the day with the
+  runner, answers.txt and the README star table.
+
+readme:
+  Regenerates the README star table from the known answers of inputs/dayN/input.txt
+  and the runtime table from benchmarks.txt. --check only fails if it is outdated.";
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Calibrate(CalibrateArgs),
+    Cubes(CubesArgs),
+    Gears(GearsArgs),
+    New(u8),
+    Readme { check: bool },
+}
+
+struct RunArgs {
+    day: u8,
+    input: Input,
+    parts: Vec<Part>,
+    format: Format,
+}
+
+enum Format {
+    Text,
+    Json,
+}
+
+enum Stream {
+    Sequential,
+    Parallel,
+}
+
+enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+struct CalibrateArgs {
+    input: Input,
+    vocabulary: String,
+    missing: day1::MissingDigits,
+    stream: Option<Stream>,
+    explain: Option<Format>,
+}
+
+struct CubesArgs {
+    input: Input,
+    import: day2::format::LogFormat,
+    bag: Bag,
+    mode: CubesMode,
+}
+
+enum CubesMode {
+    Solve,
+    Report(Format),
+    Estimate(u32),
+    Query(String, Format),
+    Export(day2::format::LogFormat),
+}
+
+struct GearsArgs {
+    input: Input,
+    rules: day3::rules::GearRules,
+}
+
+enum Bag {
+    Set(String),
+    File(PathBuf),
+}
+
+struct BenchArgs {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    iterations: usize,
+    slow: bool,
+    baseline: Option<PathBuf>,
+    output: PathBuf,
+    threshold: f64,
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(args) => run(&args),
+        Command::Bench(args) => run_bench(&args),
+        Command::Calibrate(args) => calibrate(&args),
+        Command::Cubes(args) => cubes(&args),
+        Command::Gears(args) => gears(&args),
+        Command::New(day) => new(day),
+        Command::Readme { check } => update_readme(check),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(String::as_str) {
+        Some("run") => parse_run_args(&args[1..]).map(Command::Run),
+        Some("bench") => parse_bench_args(&args[1..]).map(Command::Bench),
+        Some("calibrate") => parse_calibrate_args(&args[1..]).map(Command::Calibrate),
+        Some("cubes") => parse_cubes_args(&args[1..]).map(Command::Cubes),
+        Some("gears") => parse_gears_args(&args[1..]).map(Command::Gears),
+        Some("new") => match &args[1..] {
+            [day] => parse_day(day).map(Command::New),
+            _ => Err("expected exactly one day".to_string()),
+        },
+        Some("readme") => match &args[1..] {
+            [] => Ok(Command::Readme { check: false }),
+            [check] if check == "--check" => Ok(Command::Readme { check: true }),
+            _ => Err("expected only `--check`".to_string()),
+        },
+        Some(command) => Err(format!("unknown command `{command}`")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse().map_err(|e| format!("invalid day `{day}`: {e}"))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut args = args.iter().peekable();
+    let day = parse_day(args.next().ok_or("missing day")?)?;
+
+    let mut input = None;
+    let mut parts = Part::ALL.to_vec();
+    let mut format = Format::Text;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![args.next().ok_or("missing part")?.parse()?],
+            "--format" => format = parse_format(args.next())?,
+            _ if parse_input_arg(day, arg, &mut args, &mut input)? => {}
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(RunArgs {
+        day,
+        input: input.unwrap_or_else(|| Input::File(inputs::puzzle(day))),
+        parts,
+        format,
+    })
+}
+
+fn parse_format(format: Option<&String>) -> Result<Format, String> {
+    match format.map(String::as_str) {
+        Some("text") => Ok(Format::Text),
+        Some("json") => Ok(Format::Json),
+        _ => Err("format must be `text` or `json`".to_string()),
+    }
+}
+
+/// Handles `arg` if it selects the input, `INPUT`, `-` or `--example [N]`. Returns whether it did.
+fn parse_input_arg<'a>(
+    day: u8,
+    arg: &str,
+    args: &mut Peekable<impl Iterator<Item = &'a String>>,
+    input: &mut Option<Input>,
+) -> Result<bool, String> {
+    let selected = match arg {
+        "--example" => {
+            let n = match args.next_if(|n| n.parse::<u32>().is_ok()) {
+                Some(n) => n.parse().unwrap(),
+                None => 1,
+            };
+            Input::File(inputs::example(day, n))
+        }
+        "-" => Input::Stdin,
+        path if !path.starts_with("--") => Input::File(path.into()),
+        _ => return Ok(false),
+    };
+    if input.replace(selected).is_some() {
+        return Err("more than one input given".to_string());
+    }
+    Ok(true)
+}
+
+fn parse_calibrate_args(args: &[String]) -> Result<CalibrateArgs, String> {
+    let mut args = args.iter().peekable();
+    let mut input = None;
+    let mut vocabulary = None;
+    let mut extended = false;
+    let mut missing = day1::MissingDigits::Error;
+    let mut stream = None;
+    let mut explain = false;
+    let mut format = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--vocabulary" => {
+                vocabulary = Some(args.next().ok_or("missing vocabulary")?.clone());
+            }
+            "--extended" => extended = true,
+            "--missing" => missing = args.next().ok_or("missing policy")?.parse()?,
+            "--stream" => stream = stream.or(Some(Stream::Sequential)),
+            "--parallel" => stream = Some(Stream::Parallel),
+            "--explain" => explain = true,
+            "--format" => format = Some(parse_format(args.next())?),
+            _ if parse_input_arg(day1::Day1::DAY, arg, &mut args, &mut input)? => {}
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    if format.is_some() && !explain {
+        return Err("--format needs --explain".to_string());
+    }
+    let vocabulary = match vocabulary {
+        Some(vocabulary) if extended && vocabulary != "english" => {
+            return Err("--extended only applies to the english vocabulary".to_string())
+        }
+        _ if extended => "english-extended".to_string(),
+        vocabulary => vocabulary.unwrap_or_else(|| "english".to_string()),
+    };
+    if explain && stream.is_some() {
+        return Err("--explain can not be streamed".to_string());
+    }
+
+    Ok(CalibrateArgs {
+        input: input.unwrap_or_else(|| Input::File(inputs::puzzle(day1::Day1::DAY))),
+        vocabulary,
+        missing,
+        stream,
+        explain: explain.then(|| format.unwrap_or(Format::Text)),
+    })
+}
+
+fn parse_cubes_args(args: &[String]) -> Result<CubesArgs, String> {
+    let mut args = args.iter().peekable();
+    let mut input = None;
+    let mut bag = None;
+    let mut report = false;
+    let mut format = None;
+    let mut estimate = false;
+    let mut limit = None;
+    let mut query = None;
+    let mut import = None;
+    let mut export = None;
+    while let Some(arg) = args.next() {
+        let previous = match arg.as_str() {
+            "--bag" => bag.replace(Bag::Set(args.next().ok_or("missing bag")?.clone())),
+            "--bag-file" => bag.replace(Bag::File(args.next().ok_or("missing bag file")?.into())),
+            "--report" => {
+                report = true;
+                None
+            }
+            "--format" => {
+                format = Some(parse_format(args.next())?);
+                None
+            }
+            "--estimate" => {
+                estimate = true;
+                None
+            }
+            "--limit" => {
+                let n = args.next().ok_or("missing limit")?;
+                limit = Some(n.parse().map_err(|_| format!("invalid limit `{n}`"))?);
+                None
+            }
+            "--query" => {
+                query = Some(args.next().ok_or("missing query")?.clone());
+                None
+            }
+            "--import" => {
+                import = Some(args.next().ok_or("missing log format")?.parse()?);
+                None
+            }
+            "--export" => {
+                export = Some(args.next().ok_or("missing log format")?.parse()?);
+                None
+            }
+            _ if parse_input_arg(day2::Day2::DAY, arg, &mut args, &mut input)? => None,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        };
+        if previous.is_some() {
+            return Err("more than one bag given".to_string());
+        }
+    }
+    if format.is_some() && !report && query.is_none() {
+        return Err("--format needs --report or --query".to_string());
+    }
+    if limit.is_some() && !estimate {
+        return Err("--limit needs --estimate".to_string());
+    }
+    let format = format.unwrap_or(Format::Text);
+    let mode = match (report, estimate, query, export) {
+        (false, false, None, None) => CubesMode::Solve,
+        (true, false, None, None) => CubesMode::Report(format),
+        (false, true, None, None) => CubesMode::Estimate(limit.unwrap_or(1000)),
+        (false, false, Some(query), None) => CubesMode::Query(query, format),
+        (false, false, None, Some(export)) => CubesMode::Export(export),
+        _ => {
+            return Err("--report, --estimate, --query and --export exclude each other".to_string())
+        }
+    };
+
+    Ok(CubesArgs {
+        input: input.unwrap_or_else(|| Input::File(inputs::puzzle(day2::Day2::DAY))),
+        import: import.unwrap_or(day2::format::LogFormat::Text),
+        bag: bag.unwrap_or_else(|| Bag::Set("12 red, 13 green, 14 blue".to_string())),
+        mode,
+    })
+}
+
+fn parse_gears_args(args: &[String]) -> Result<GearsArgs, String> {
+    let mut args = args.iter().peekable();
+    let mut input = None;
+    let mut rules = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => {
+                let text = args.next().ok_or("missing rules")?;
+                let parsed = text.parse().map_err(|e| format!("invalid rules: {e}"))?;
+                if rules.replace(parsed).is_some() {
+                    return Err("more than one set of rules given".to_string());
+                }
+            }
+            _ if parse_input_arg(day3::Day3::DAY, arg, &mut args, &mut input)? => {}
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(GearsArgs {
+        input: input.unwrap_or_else(|| Input::File(inputs::puzzle(day3::Day3::DAY))),
+        rules: rules.unwrap_or_default(),
+    })
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench = BenchArgs {
+        days: Vec::new(),
+        parts: Part::ALL.to_vec(),
+        iterations: 10,
+        slow: false,
+        baseline: None,
+        output: PathBuf::from(bench::PATH),
+        threshold: 10.0,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value of `{arg}`"));
+        match arg.as_str() {
+            "--part" => bench.parts = vec![value()?.parse()?],
+            "--iterations" => {
+                bench.iterations = value()?
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or("iterations must be a positive number")?
+            }
+            "--slow" => bench.slow = true,
+            "--baseline" => bench.baseline = Some(value()?.into()),
+            "--output" => bench.output = value()?.into(),
+            "--threshold" => bench.threshold = value()?.parse().map_err(|_| "invalid threshold")?,
+            day if !day.starts_with("--") => bench.days.push(parse_day(day)?),
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(bench)
+}
+
+fn run(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let day = days::get(args.day).ok_or(format!("day {} is not solved yet", args.day))?;
+
+    let input = read(&args.input)?;
+    let run = (day.run)(&mut input.as_slice(), &args.parts)?;
+
+    let failed = run
+        .parts
+        .iter()
+        .filter(|solved| solved.answer.is_err())
+        .count();
+    for solved in &run.parts {
+        match (&args.format, &solved.answer) {
+            (Format::Text, Ok(answer)) => {
+                println!("Day {}, part {}: {answer}", day.number, solved.part)
+            }
+            (Format::Text, Err(e)) => {
+                eprintln!(
+                    "Day {}, part {} failed: {}",
+                    day.number, solved.part, e.reason
+                )
+            }
+            (Format::Json, answer) => println!(
+                "{}",
+                json!({
+                    "day": day.number,
+                    "part": u8::from(solved.part),
+                    "answer": answer.as_ref().ok(),
+                    "error": answer.as_ref().err().map(|e| &e.reason),
+                    "duration_ns": solved.duration.as_nanos() as u64,
+                    "input_hash": format!("{:016x}", inputs::hash(&input)),
+                })
+            ),
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{failed} of {} parts have no answer", run.parts.len()).into());
+    }
+    Ok(())
+}
+
+fn read(input: &Input) -> Result<Vec<u8>, Box<dyn Error>> {
+    Ok(match input {
+        Input::Stdin => {
+            let mut input = Vec::new();
+            io::stdin().lock().read_to_end(&mut input)?;
+            input
+        }
+        Input::File(path) => fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?,
+    })
+}
+
+fn calibrate(args: &CalibrateArgs) -> Result<(), Box<dyn Error>> {
+    let vocabulary = day1::Vocabulary::load(&args.vocabulary)?;
+    if let Some(stream) = &args.stream {
+        let parallel = matches!(stream, Stream::Parallel);
+        let summary = match &args.input {
+            Input::Stdin => {
+                day1::calibrate_stream(io::stdin().lock(), &vocabulary, args.missing, parallel)?
+            }
+            Input::File(path) => {
+                let file = fs::File::open(path).map_err(|e| format!("{}: {e}", path.display()))?;
+                day1::calibrate_stream(file, &vocabulary, args.missing, parallel)?
+            }
+        };
+        print_summary(&summary, args.missing);
+        return Ok(());
+    }
+
+    let lines = day1::Day1::parse(read(&args.input)?.as_slice())?;
+    match &args.explain {
+        None => print_summary(
+            &day1::calibrate(&lines, &vocabulary, args.missing)?,
+            args.missing,
+        ),
+        Some(format) => explain(&lines, &day1::explain(&lines, &vocabulary), format),
+    }
+    Ok(())
+}
+
+fn print_summary(summary: &day1::Summary, policy: day1::MissingDigits) {
+    println!("{}", summary.sum);
+    if summary.missing > 0 {
+        let more = summary.missing - summary.listed.len();
+        eprintln!(
+            "{} of {} lines without digits {}: {}{}",
+            summary.missing,
+            summary.calibrated + summary.missing,
+            match policy {
+                day1::MissingDigits::Skip => "skipped",
+                _ => "counted as 0",
+            },
+            summary.listed.iter().join(", "),
+            if more > 0 {
+                format!(" and {more} more")
+            } else {
+                String::new()
+            }
+        );
+    }
+}
+
+fn explain(lines: &[String], explanations: &[day1::Explanation], format: &Format) {
+    if let Format::Text = format {
+        println!(
+            "{:>5}  {:<18} {:>6}  {:<18} {:>6}  {:>5}",
+            "line", "first", "offset", "last", "offset", "value"
+        );
+    }
+    for (line, explanation) in lines.iter().zip(explanations) {
+        let value = explanation.value();
+        match format {
+            Format::Text => {
+                let token = |token: Option<day1::Token>| match token {
+                    Some(t) => (
+                        format!("{} ({})", t.text(line), t.kind),
+                        t.offset.to_string(),
+                    ),
+                    None => ("-".to_string(), "-".to_string()),
+                };
+                let (first, first_offset) = token(explanation.first);
+                let (last, last_offset) = token(explanation.last);
+                println!(
+                    "{:>5}  {first:<18} {first_offset:>6}  {last:<18} {last_offset:>6}  {:>5}",
+                    explanation.line,
+                    value.map_or("-".to_string(), |v| v.to_string()),
+                );
+            }
+            Format::Json => {
+                let token = |token: Option<day1::Token>| {
+                    token.map(|t| {
+                        json!({
+                            "text": t.text(line),
+                            "offset": t.offset,
+                            "kind": t.kind.to_string(),
+                            "value": t.value,
+                        })
+                    })
+                };
+                println!(
+                    "{}",
+                    json!({
+                        "line": explanation.line,
+                        "first": token(explanation.first),
+                        "last": token(explanation.last),
+                        "value": value,
+                    })
+                );
+            }
+        }
+    }
+}
+
+fn cubes(args: &CubesArgs) -> Result<(), Box<dyn Error>> {
+    let bag = match &args.bag {
+        Bag::Set(set) => set.parse().map_err(|e| format!("invalid bag: {e}"))?,
+        Bag::File(path) => day2::CubeSet::load(path)?,
+    };
+    let log = String::from_utf8(read(&args.input)?)?;
+    let games = day2::format::read_log(&log, &bag, args.import)?;
+    match &args.mode {
+        CubesMode::Solve => {
+            println!("Day 2, part 1: {}", day2::possible_ids_sum(&games, &bag));
+            let powers =
+                day2::power_sum(&games, &bag).map_err(|i| day2::power_overflow(&games, i))?;
+            println!("Day 2, part 2: {powers}");
+        }
+        CubesMode::Report(format) => report(&games, &bag, format),
+        CubesMode::Estimate(limit) => estimate(&games, *limit)?,
+        CubesMode::Query(query, format) => {
+            let query = day2::query::Query::parse(query, &bag)
+                .map_err(|e| format!("invalid query: {e}"))?;
+            let matches = query.run(&games)?;
+            match format {
+                Format::Text => {
+                    println!("games: {}", matches.ids.iter().join(", "));
+                    println!("count: {}", matches.ids.len());
+                    println!("sum of ids: {}", matches.id_sum);
+                    println!("sum of powers: {}", matches.power_sum);
+                }
+                Format::Json => println!(
+                    "{}",
+                    json!({
+                        "ids": matches.ids,
+                        "count": matches.ids.len(),
+                        "id_sum": matches.id_sum,
+                        "power_sum": matches.power_sum,
+                    })
+                ),
+            }
+        }
+        CubesMode::Export(format) => print!("{}", day2::format::write_log(&games, *format)),
+    }
+    Ok(())
+}
+
+fn gears(args: &GearsArgs) -> Result<(), Box<dyn Error>> {
+    let schematic = day3::read_schematic(read(&args.input)?.as_slice())?;
+    let graph = schematic.graph(&args.rules);
+    let parts = graph.edges().map(|(_, number)| u64::from(number.value));
+    println!("Day 3, part 1: {}", parts.sum::<u64>());
+    let ratios = graph
+        .gear_ratio_sum(&args.rules)
+        .map_err(|gear| day3::ratio_overflow(&graph, gear))?;
+    println!("Day 3, part 2: {ratios}");
+    for gear in graph.ambiguous_gears(&args.rules) {
+        let symbol = graph.symbols()[gear];
+        let numbers = graph.numbers_of(gear).map(|number| number.value).join(", ");
+        eprintln!(
+            "warning: ambiguous gear `{}` at line {}, column {} touches {numbers}",
+            symbol.character,
+            symbol.position.y + 1,
+            symbol.position.x + 1
+        );
+    }
+    Ok(())
+}
+
+fn estimate(games: &[day2::Game], limit: u32) -> Result<(), Box<dyn Error>> {
+    let estimate = day2::estimate_bag(games, limit)?;
+    println!(
+        "{:<10} {:>8} {:>8} {:>8}",
+        "colour", "estimate", "lower", "upper"
+    );
+    for c in &estimate.colours {
+        let upper = c.upper.map_or("-".to_string(), |upper| upper.to_string());
+        println!("{:<10} {:>8} {:>8} {upper:>8}", c.colour, c.count, c.lower);
+    }
+    println!("log-likelihood: {:.3}", estimate.log_likelihood);
+    if estimate.limited {
+        eprintln!("warning: the estimate reaches the limit of {limit} cubes, raise --limit");
+    }
+    Ok(())
+}
+
+fn report(games: &[day2::Game], bag: &day2::CubeSet, format: &Format) {
+    match format {
+        Format::Text => {
+            println!("Impossible games:");
+            println!(
+                "{:>5} {:>4}  {:<10} {:>6} {:>6}",
+                "game", "set", "colour", "drawn", "limit"
+            );
+            for game in games {
+                for v in game.violations(bag) {
+                    println!(
+                        "{:>5} {:>4}  {:<10} {:>6} {:>6}",
+                        game.id, v.set, v.colour, v.drawn, v.limit
+                    );
+                }
+            }
+            println!();
+            println!("Minimal bags:");
+            println!("{:>5}  {:<32} {:>8}", "game", "bag", "power");
+            for game in games {
+                let minimal = game.minimal_bag();
+                let power = minimal
+                    .power(bag.colours())
+                    .map_or("overflow".to_string(), |power| power.to_string());
+                println!("{:>5}  {:<32} {power:>8}", game.id, minimal.to_string());
+            }
+        }
+        Format::Json => {
+            for game in games {
+                let violations = game
+                    .violations(bag)
+                    .map(|v| {
+                        json!({
+                            "set": v.set,
+                            "colour": v.colour,
+                            "drawn": v.drawn,
+                            "limit": v.limit,
+                        })
+                    })
+                    .collect_vec();
+                let minimal = game.minimal_bag();
+                println!(
+                    "{}",
+                    json!({
+                        "game": game.id,
+                        "possible": violations.is_empty(),
+                        "violations": violations,
+                        "minimal_bag": minimal.iter().collect::<BTreeMap<_, _>>(),
+                        "power": minimal.power(bag.colours()),
+                    })
+                );
+            }
+        }
+    }
+}
+
+fn run_bench(args: &BenchArgs) -> Result<(), Box<dyn Error>> {
+    if cfg!(debug_assertions) {
+        // debug timings would make every later release run look like an improvement
+        if args.output == Path::new(bench::PATH) {
+            return Err(format!(
+                "refusing to write {} from a debug build, use `cargo run --release` or --output",
+                bench::PATH
+            )
+            .into());
+        }
+        eprintln!("warning: benchmarking a debug build, use `cargo run --release`");
+    }
+
+    let selected = if args.days.is_empty() {
+        days::DAYS.iter().collect::<Vec<_>>()
+    } else {
+        args.days
+            .iter()
+            .map(|&n| days::get(n).ok_or(format!("day {n} is not solved yet")))
+            .collect::<Result<_, _>>()?
+    };
+    let answers = answers::load(answers::PATH)?;
+    let baseline = match &args.baseline {
+        Some(path) => bench::load(path)?,
+        None if args.output.exists() => bench::load(&args.output)?,
+        None => Vec::new(),
+    };
+
+    println!(
+        "{:>4} {:<6} {:>12} {:>12} {:>12} {:>9}",
+        "day", "phase", "min", "median", "max", "change"
+    );
+    let mut timings = Vec::new();
+    let mut regressions = 0;
+    for day in selected {
+        let parts = args
+            .parts
+            .iter()
+            .copied()
+            .filter(|&part| {
+                answers.iter().any(|a| {
+                    (a.day, a.input.as_str(), a.part) == (day.number, "input.txt", part)
+                        && a.answer.is_some()
+                        && (args.slow || !a.slow)
+                })
+            })
+            .collect::<Vec<_>>();
+        let path = inputs::puzzle(day.number);
+        let input = fs::read(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+        for timing in bench::measure(day, &input, &parts, args.iterations)? {
+            let change = bench::change(&baseline, &timing);
+            let regression = change.is_some_and(|c| c * 100.0 > args.threshold);
+            regressions += usize::from(regression);
+            println!(
+                "{:>4} {:<6} {:>12} {:>12} {:>12} {:>9}{}",
+                timing.day,
+                timing.phase.to_string(),
+                format!("{:.2?}", timing.min),
+                format!("{:.2?}", timing.median),
+                format!("{:.2?}", timing.max),
+                change.map_or(String::new(), |c| format!("{:+.1}%", c * 100.0)),
+                if regression { "  REGRESSION" } else { "" }
+            );
+            timings.push(timing);
+        }
+    }
+
+    let previous = bench::load_existing(&args.output)?;
+    fs::write(
+        &args.output,
+        bench::to_text(&bench::merge(&previous, &timings)),
+    )?;
+
+    if regressions > 0 {
+        return Err(format!(
+            "{regressions} phases regressed by more than {}%",
+            args.threshold
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn new(day: u8) -> Result<(), Box<dyn Error>> {
+    for path in scaffold::new_day(day)? {
+        println!("{}", path.display());
+    }
+    println!("Done! Happy Advent!");
+    Ok(())
+}
+
+fn update_readme(check: bool) -> Result<(), Box<dyn Error>> {
+    let current = fs::read_to_string(readme::PATH)?;
+    let timings = bench::load_existing(bench::PATH)?;
+    let updated = readme::regenerate(&current, &fs::read_to_string(answers::PATH)?, &timings)?;
+    if check {
+        if current != updated {
+            return Err(format!("{} is outdated, run `aoc readme`", readme::PATH).into());
+        }
+    } else {
+        fs::write(readme::PATH, updated)?;
+    }
+    Ok(())
+}
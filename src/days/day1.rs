@@ -0,0 +1,844 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, BufRead, Read},
+    str::{self, FromStr},
+};
+
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use crate::{parse, ParseError, Part, Solution, SolveError};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+}
+
+/// Number words, matched in addition to the digits themselves. Words may stand for numbers with
+/// more than one digit: as the first token of a line they count with their leading digit, as the
+/// last one with their units digit, so `twenty-one` alone calibrates to 21 and `seventeen` to 17.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Vocabulary {
+    /// Sorted by decreasing length, so that the longest of the words matching at a position wins.
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// Names accepted by [`Vocabulary::builtin`].
+    pub const BUILTIN: [&'static str; 5] =
+        ["digits", "english", "english-extended", "polish", "german"];
+
+    /// Creates a vocabulary, rejecting empty or duplicate words and words containing digits.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u32)>) -> Result<Self, String> {
+        let mut words = words
+            .into_iter()
+            .map(|(word, value)| (word.into(), value))
+            .collect::<Vec<_>>();
+        for (word, _) in &words {
+            if word.is_empty() || word.bytes().any(|b| b.is_ascii_digit()) {
+                return Err(format!(
+                    "invalid word `{word}`, it must be non-empty without digits"
+                ));
+            }
+        }
+        words.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then(a.cmp(b)));
+        if let Some(((word, _), _)) = words.iter().tuple_windows().find(|(a, b)| a.0 == b.0) {
+            return Err(format!("duplicate word `{word}`"));
+        }
+        Ok(Vocabulary { words })
+    }
+
+    /// No words at all, only digits count.
+    pub fn digits() -> Self {
+        Vocabulary::default()
+    }
+
+    pub fn english() -> Self {
+        Self::builtin("english").unwrap()
+    }
+
+    /// The English words for all numbers from 1 to 99, including the teens, the tens and
+    /// hyphenated compounds like `twenty-one`. Not part of the puzzle, which only spells out digits.
+    pub fn english_extended() -> Self {
+        Self::builtin("english-extended").unwrap()
+    }
+
+    /// One of the [`Vocabulary::BUILTIN`] vocabularies.
+    pub fn builtin(name: &str) -> Option<Self> {
+        const ONES: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        const TEENS: [&str; 10] = [
+            "ten",
+            "eleven",
+            "twelve",
+            "thirteen",
+            "fourteen",
+            "fifteen",
+            "sixteen",
+            "seventeen",
+            "eighteen",
+            "nineteen",
+        ];
+        const TENS: [&str; 8] = [
+            "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+        ];
+
+        let words: &[&str] = match name {
+            "digits" => &[],
+            "english" => &ONES,
+            "english-extended" => {
+                let ones = ONES.iter().zip(1..).map(|(&word, n)| (word.to_string(), n));
+                let teens = TEENS
+                    .iter()
+                    .zip(10..)
+                    .map(|(&word, n)| (word.to_string(), n));
+                let tens = TENS.iter().zip((20..).step_by(10)).flat_map(|(&ten, n)| {
+                    let compounds = ONES
+                        .iter()
+                        .zip(1..)
+                        .map(move |(one, m)| (format!("{ten}-{one}"), n + m));
+                    std::iter::once((ten.to_string(), n)).chain(compounds)
+                });
+                return Some(Self::new(ones.chain(teens).chain(tens)).unwrap());
+            }
+            "polish" => &[
+                "jeden",
+                "dwa",
+                "trzy",
+                "cztery",
+                "pięć",
+                "sześć",
+                "siedem",
+                "osiem",
+                "dziewięć",
+            ],
+            "german" => &[
+                "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+            ],
+            _ => return None,
+        };
+        Some(Self::new(words.iter().copied().zip(1..)).unwrap())
+    }
+
+    /// Parses lines of a word and its number, separated by whitespace. Empty lines and lines
+    /// starting with `#` are ignored.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let words = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(
+                |(i, line)| match line.split_whitespace().collect_vec().as_slice() {
+                    [word, value] => value
+                        .parse()
+                        .map(|value| (word.to_string(), value))
+                        .map_err(|_| format!("{}: invalid number `{value}`", i + 1)),
+                    _ => Err(format!("{}: expected `word number`", i + 1)),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(words)
+    }
+
+    /// A built-in vocabulary by name, otherwise a file in the format read by [`Vocabulary::parse`].
+    pub fn load(name_or_path: &str) -> Result<Self, String> {
+        if let Some(vocabulary) = Self::builtin(name_or_path) {
+            return Ok(vocabulary);
+        }
+        let text = fs::read_to_string(name_or_path).map_err(|e| {
+            format!(
+                "{name_or_path}: {e}, expected a file or one of {}",
+                Self::BUILTIN.join(", ")
+            )
+        })?;
+        Self::parse(&text).map_err(|e| format!("{name_or_path}:{e}"))
+    }
+
+    /// Length and value of the longest word `matches` accepts.
+    fn find(&self, matches: impl Fn(&[u8]) -> bool) -> Option<(usize, u32)> {
+        self.words
+            .iter()
+            .find(|(word, _)| matches(word.as_bytes()))
+            .map(|(word, value)| (word.len(), *value))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Digit,
+    Word,
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Digit => write!(f, "digit"),
+            TokenKind::Word => write!(f, "word"),
+        }
+    }
+}
+
+/// A digit or number word matched in a line, `offset` and `len` are in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub len: usize,
+    pub kind: TokenKind,
+    pub value: u32,
+}
+
+impl Token {
+    fn digit(bytes: &[u8], offset: usize) -> Option<Self> {
+        let byte = bytes[offset];
+        byte.is_ascii_digit().then(|| Token {
+            offset,
+            len: 1,
+            kind: TokenKind::Digit,
+            value: u32::from(byte - b'0'),
+        })
+    }
+
+    fn word(offset: usize, (len, value): (usize, u32)) -> Self {
+        Token {
+            offset,
+            len,
+            kind: TokenKind::Word,
+            value,
+        }
+    }
+
+    /// Leading digit of the value, which it contributes as the first token of a line.
+    pub fn first_digit(&self) -> u32 {
+        let mut digit = self.value;
+        while digit >= 10 {
+            digit /= 10;
+        }
+        digit
+    }
+
+    /// Units digit of the value, which it contributes as the last token of a line.
+    pub fn last_digit(&self) -> u32 {
+        self.value % 10
+    }
+
+    /// The matched part of `line`.
+    pub fn text<'a>(&self, line: &'a str) -> &'a str {
+        &line[self.offset..self.offset + self.len]
+    }
+}
+
+/// The tokens that make up the calibration value of a line, numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub line: usize,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+}
+
+impl Explanation {
+    pub fn value(&self) -> Option<u32> {
+        Some(10 * self.first?.first_digit() + self.last?.last_digit())
+    }
+}
+
+fn part1(nums: &[String]) -> Result<u64, SolveError> {
+    solve(nums, Part::One)
+}
+
+fn part2(nums: &[String]) -> Result<u64, SolveError> {
+    solve(nums, Part::Two)
+}
+
+fn vocabulary(part: Part) -> Vocabulary {
+    match part {
+        Part::One => Vocabulary::digits(),
+        Part::Two => Vocabulary::english(),
+    }
+}
+
+fn solve(nums: &[String], part: Part) -> Result<u64, SolveError> {
+    calibrate(nums, &vocabulary(part), MissingDigits::Error).map(|summary| summary.sum)
+}
+
+/// What to do with lines without any digit or number word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingDigits {
+    /// Fail with the number of the line.
+    Error,
+    /// Leave the line out.
+    Skip,
+    /// Count the line with a calibration value of 0.
+    Zero,
+}
+
+impl FromStr for MissingDigits {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(MissingDigits::Error),
+            "skip" => Ok(MissingDigits::Skip),
+            "zero" => Ok(MissingDigits::Zero),
+            _ => Err(format!(
+                "invalid policy `{s}`, expected `error`, `skip` or `zero`"
+            )),
+        }
+    }
+}
+
+/// Outcome of [`calibrate`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Summary {
+    pub sum: u64,
+    /// Number of lines with a calibration value.
+    pub calibrated: usize,
+    /// Number of lines without digits the policy applied to.
+    pub missing: usize,
+    /// The first [`LISTED_MISSING`] of those lines, numbered from 1.
+    pub listed: Vec<usize>,
+}
+
+/// How many lines without digits a [`Summary`] lists, so that it takes the same memory for
+/// any input.
+pub const LISTED_MISSING: usize = 20;
+
+impl Summary {
+    fn add_missing(&mut self, line: usize) {
+        self.missing += 1;
+        if self.listed.len() < LISTED_MISSING {
+            self.listed.push(line);
+        }
+    }
+
+    /// Adds the summary of the lines following the ones of `self`.
+    fn merge(&mut self, next: Summary) {
+        self.sum += next.sum;
+        self.calibrated += next.calibrated;
+        self.missing += next.missing;
+        let room = LISTED_MISSING - self.listed.len();
+        self.listed.extend(next.listed.into_iter().take(room));
+    }
+}
+
+/// Sums the calibration values of all `lines`, handling lines without digits according to
+/// `policy`.
+pub fn calibrate(
+    lines: &[String],
+    vocabulary: &Vocabulary,
+    policy: MissingDigits,
+) -> Result<Summary, SolveError> {
+    calibrate_lines(lines.iter().map(String::as_bytes), 0, vocabulary, policy)
+}
+
+/// Like [`calibrate`], numbering the lines after the `skipped` ones.
+fn calibrate_lines<'a>(
+    lines: impl Iterator<Item = &'a [u8]>,
+    skipped: usize,
+    vocabulary: &Vocabulary,
+    policy: MissingDigits,
+) -> Result<Summary, SolveError> {
+    let mut summary = Summary::default();
+    for (i, line) in lines.enumerate() {
+        match calibration_bytes(line, vocabulary) {
+            Some(value) => {
+                summary.sum += u64::from(value);
+                summary.calibrated += 1;
+            }
+            None if policy == MissingDigits::Error => {
+                let missing = if vocabulary.words.is_empty() {
+                    "digit"
+                } else {
+                    "digit or number word"
+                };
+                return Err(SolveError::new(
+                    Day1::DAY,
+                    format!("line {} has no {missing}", skipped + i + 1),
+                ));
+            }
+            None => summary.add_missing(skipped + i + 1),
+        }
+    }
+    Ok(summary)
+}
+
+/// Size of the blocks [`calibrate_stream`] reads at once.
+const BLOCK: usize = 1 << 20;
+
+/// Parts of a block are only worth handing to other threads above this size.
+const MIN_PARALLEL: usize = 1 << 16;
+
+/// Error of [`calibrate_stream`].
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{e}"),
+            StreamError::Parse(e) => write!(f, "{e}"),
+            StreamError::Solve(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+impl From<SolveError> for StreamError {
+    fn from(e: SolveError) -> Self {
+        StreamError::Solve(e)
+    }
+}
+
+/// Same as [`calibrate`] over the lines of `reader`, read in blocks of a fixed size. Like
+/// [`Day1::parse`], it rejects the first line that is not UTF-8. Memory use only grows with the
+/// longest line. With `parallel`, the lines of each block are split across the rayon thread
+/// pool.
+pub fn calibrate_stream<R: Read>(
+    reader: R,
+    vocabulary: &Vocabulary,
+    policy: MissingDigits,
+    parallel: bool,
+) -> Result<Summary, StreamError> {
+    calibrate_blocks(reader, BLOCK, vocabulary, policy, parallel)
+}
+
+fn calibrate_blocks<R: Read>(
+    mut reader: R,
+    block: usize,
+    vocabulary: &Vocabulary,
+    policy: MissingDigits,
+    parallel: bool,
+) -> Result<Summary, StreamError> {
+    let mut summary = Summary::default();
+    let mut buffer = vec![0; block];
+    let mut filled = 0;
+    loop {
+        if filled == buffer.len() {
+            // a line longer than the buffer
+            buffer.resize(2 * buffer.len(), 0);
+        }
+        let read = match reader.read(&mut buffer[filled..]) {
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        filled += read;
+
+        // only complete lines, unless the input ended
+        let end = match memchr::memrchr(b'\n', &buffer[..filled]) {
+            _ if read == 0 => filled,
+            Some(newline) => newline + 1,
+            None => continue,
+        };
+        let skipped = summary.calibrated + summary.missing;
+        summary.merge(calibrate_block(
+            &buffer[..end],
+            skipped,
+            vocabulary,
+            policy,
+            parallel,
+        )?);
+
+        if read == 0 {
+            return Ok(summary);
+        }
+        buffer.copy_within(end..filled, 0);
+        filled -= end;
+    }
+}
+
+/// Calibrates whole lines, the last one may lack its newline.
+fn calibrate_block(
+    block: &[u8],
+    skipped: usize,
+    vocabulary: &Vocabulary,
+    policy: MissingDigits,
+    parallel: bool,
+) -> Result<Summary, StreamError> {
+    if !parallel || block.len() < 2 * MIN_PARALLEL {
+        check_utf8(block, skipped)?;
+        return Ok(calibrate_lines(lines(block), skipped, vocabulary, policy)?);
+    }
+
+    // split after newlines into parts of roughly equal size, one per thread
+    let size = (block.len() / rayon::current_num_threads()).max(MIN_PARALLEL);
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut skipped = skipped;
+    while start < block.len() {
+        let split = (start + size).min(block.len());
+        let end = memchr::memchr(b'\n', &block[split..])
+            .map_or(block.len(), |newline| split + newline + 1);
+        parts.push((&block[start..end], skipped));
+        skipped += memchr::memchr_iter(b'\n', &block[start..end]).count();
+        start = end;
+    }
+
+    parts
+        .into_par_iter()
+        .map(|(part, skipped)| {
+            check_utf8(part, skipped)?;
+            Ok(calibrate_lines(lines(part), skipped, vocabulary, policy)?)
+        })
+        .collect::<Vec<Result<_, StreamError>>>()
+        .into_iter()
+        .try_fold(Summary::default(), |mut summary, part| {
+            summary.merge(part?);
+            Ok(summary)
+        })
+}
+
+/// Rejects the first line of `block` that is not UTF-8, numbered after the `skipped` ones.
+fn check_utf8(block: &[u8], skipped: usize) -> Result<(), ParseError> {
+    let Err(e) = str::from_utf8(block) else {
+        return Ok(());
+    };
+    let start = memchr::memrchr(b'\n', &block[..e.valid_up_to()]).map_or(0, |newline| newline + 1);
+    let index = skipped + memchr::memchr_iter(b'\n', &block[..start]).count();
+    let line = lines(&block[start..]).next().unwrap_or_default();
+    Err(parse::utf8_error(Day1::DAY, index, line))
+}
+
+/// Lines of `bytes` without their `\n` or `\r\n`, like [`BufRead::lines`].
+fn lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let last = (!bytes.is_empty() && !bytes.ends_with(b"\n")).then_some(bytes.len());
+    let mut start = 0;
+    memchr::memchr_iter(b'\n', bytes)
+        .chain(last)
+        .map(move |end| {
+            let line = &bytes[start..end];
+            start = end + 1;
+            line.strip_suffix(b"\r").unwrap_or(line)
+        })
+}
+
+/// Two-digit value of the first and last digit or word of `line`, `None` if there is none.
+pub fn calibration(line: &str, vocabulary: &Vocabulary) -> Option<u32> {
+    calibration_bytes(line.as_bytes(), vocabulary)
+}
+
+fn calibration_bytes(line: &[u8], vocabulary: &Vocabulary) -> Option<u32> {
+    let first = first_token(line, vocabulary)?;
+    let last = last_token(line, vocabulary)?;
+    Some(10 * first.first_digit() + last.last_digit())
+}
+
+/// Which tokens every line starts and ends with.
+pub fn explain(lines: &[String], vocabulary: &Vocabulary) -> Vec<Explanation> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| Explanation {
+            line: i + 1,
+            first: first_token(line.as_bytes(), vocabulary),
+            last: last_token(line.as_bytes(), vocabulary),
+        })
+        .collect()
+}
+
+/// The match starting leftmost. Matches may overlap, so in `twone` it is `two`.
+fn first_token(bytes: &[u8], vocabulary: &Vocabulary) -> Option<Token> {
+    (0..bytes.len()).find_map(|i| {
+        Token::digit(bytes, i).or_else(|| {
+            vocabulary
+                .find(|word| bytes[i..].starts_with(word))
+                .map(|word| Token::word(i, word))
+        })
+    })
+}
+
+/// The match ending rightmost. Matches may overlap, so in `twone` it is `one`.
+fn last_token(bytes: &[u8], vocabulary: &Vocabulary) -> Option<Token> {
+    (0..bytes.len()).rev().find_map(|i| {
+        Token::digit(bytes, i).or_else(|| {
+            vocabulary
+                .find(|word| bytes[..=i].ends_with(word))
+                .map(|word| Token::word(i + 1 - word.0, word))
+        })
+    })
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<String>, ParseError> {
+    parse::lines(reader, Day1::DAY).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlapping_words() {
+        let english = Vocabulary::english();
+        for (line, expected) in [
+            ("oneight", 18),
+            ("twone", 21),
+            ("sevenine", 79),
+            ("eightwothree", 83),
+            ("xtwone3four", 24),
+            ("eighthree", 83),
+            ("nineight", 98),
+            ("fiveight2zoneight", 58),
+            ("one", 11),
+            ("7", 77),
+            ("threeight7", 37),
+            ("on2e", 22),
+            ("ninine", 99),
+            ("eeeight", 88),
+        ] {
+            assert_eq!(Some(expected), calibration(line, &english), "{line}");
+        }
+    }
+
+    #[test]
+    fn test_no_digits() {
+        let english = Vocabulary::english();
+        let digits = Vocabulary::digits();
+        assert_eq!(None, calibration("", &english));
+        assert_eq!(None, calibration("abc", &english));
+        assert_eq!(None, calibration("oneight", &digits));
+        assert_eq!(Some(33), calibration("on3ight", &digits));
+    }
+
+    #[test]
+    fn test_explain() {
+        let lines = ["a2twone".to_string(), "none".to_string()];
+        let explanations = explain(&lines, &Vocabulary::english());
+
+        let [first, second] = explanations.as_slice() else {
+            panic!("expected two explanations");
+        };
+        let digit = first.first.unwrap();
+        assert_eq!(
+            (1, 1, TokenKind::Digit, 2),
+            (digit.offset, digit.len, digit.kind, digit.value)
+        );
+        let word = first.last.unwrap();
+        assert_eq!(
+            (4, 3, TokenKind::Word, 1),
+            (word.offset, word.len, word.kind, word.value)
+        );
+        assert_eq!("one", word.text(&lines[0]));
+        assert_eq!(Some(21), first.value());
+
+        assert_eq!((2, Some(11)), (second.line, second.value()));
+        assert_eq!(1, second.first.unwrap().offset);
+    }
+
+    #[test]
+    fn test_missing_digits() {
+        let lines = ["1abc2", "", "pqr3stu8vwx", "one"].map(String::from);
+        let digits = Vocabulary::digits();
+
+        let error = calibrate(&lines, &digits, MissingDigits::Error).unwrap_err();
+        assert_eq!("day 1: line 2 has no digit", error.to_string());
+
+        let skipped = calibrate(&lines, &digits, MissingDigits::Skip).unwrap();
+        assert_eq!(
+            (50, 2, 2, vec![2, 4]),
+            (
+                skipped.sum,
+                skipped.calibrated,
+                skipped.missing,
+                skipped.listed.clone()
+            )
+        );
+        let zeroed = calibrate(&lines, &digits, MissingDigits::Zero).unwrap();
+        assert_eq!(skipped, zeroed);
+
+        let english = calibrate(&lines, &Vocabulary::english(), MissingDigits::Skip).unwrap();
+        assert_eq!(
+            (61, 1, vec![2]),
+            (english.sum, english.missing, english.listed)
+        );
+
+        // only the first lines are listed, in blocks as well
+        let input = "x\n".repeat(LISTED_MISSING + 5) + "1\n";
+        let lines = Day1::parse(input.as_bytes()).unwrap();
+        let summary = calibrate(&lines, &digits, MissingDigits::Skip).unwrap();
+        assert_eq!(
+            (LISTED_MISSING + 5, 1),
+            (summary.missing, summary.calibrated)
+        );
+        assert_eq!((1..=LISTED_MISSING).collect_vec(), summary.listed);
+        let streamed =
+            calibrate_blocks(input.as_bytes(), 8, &digits, MissingDigits::Skip, false).unwrap();
+        assert_eq!(summary, streamed);
+    }
+
+    #[test]
+    fn test_stream_rejects_invalid_utf8() {
+        let digits = Vocabulary::digits();
+        for input in [&b"12\n\xff3\n45\n"[..], b"12\n4\xc5\xbc\xc3\n45"] {
+            let expected = Day1::parse(input).map(|_| ()).unwrap_err().to_string();
+            for block in [1, 4, 64] {
+                let actual = calibrate_blocks(input, block, &digits, MissingDigits::Skip, false)
+                    .unwrap_err()
+                    .to_string();
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut input = std::fs::read("inputs/day1/input.txt").unwrap();
+        input.extend_from_slice(b"\r\nno digits\n\ntwo\r\n1\n\n");
+        let lines = Day1::parse(input.as_slice()).unwrap();
+
+        for vocabulary in Vocabulary::BUILTIN.map(|name| Vocabulary::builtin(name).unwrap()) {
+            for policy in [
+                MissingDigits::Error,
+                MissingDigits::Skip,
+                MissingDigits::Zero,
+            ] {
+                let expected = calibrate(&lines, &vocabulary, policy);
+                for block in [1, 7, 64, 1 << 12] {
+                    let actual =
+                        calibrate_blocks(input.as_slice(), block, &vocabulary, policy, false)
+                            .map_err(|e| e.to_string());
+                    assert_eq!(expected.clone().map_err(|e| e.to_string()), actual);
+                }
+
+                // large enough to be split across the threads
+                let large = input.repeat(16);
+                let expected =
+                    calibrate(&Day1::parse(large.as_slice()).unwrap(), &vocabulary, policy);
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(4)
+                    .build()
+                    .unwrap();
+                let actual = pool
+                    .install(|| calibrate_stream(large.as_slice(), &vocabulary, policy, true))
+                    .map_err(|e| e.to_string());
+                assert_eq!(expected.map_err(|e| e.to_string()), actual);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        let expected: [&[u8]; 3] = [b"a", b"", b"b"];
+        assert_eq!(expected.to_vec(), lines(b"a\r\n\nb").collect::<Vec<_>>());
+        assert_eq!(expected.to_vec(), lines(b"a\n\nb\n").collect::<Vec<_>>());
+        assert_eq!(0, lines(b"").count());
+    }
+
+    #[test]
+    fn test_digitless_parts() {
+        let input = Day1::parse(&b"1abc2\ntwo\n"[..]).unwrap();
+        assert_eq!(Ok(34), Day1::part2(&input));
+        let error = Day1::part1(&input).unwrap_err();
+        assert_eq!("day 1: line 2 has no digit", error.to_string());
+
+        // part 1 failing does not keep part 2 from being solved
+        let run = crate::run::<Day1>(&mut &b"1abc2\ntwo\n"[..], &Part::ALL).unwrap();
+        assert_eq!(
+            vec![Err(error), Ok("34".to_string())],
+            run.parts
+                .into_iter()
+                .map(|solved| solved.answer)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let polish = Vocabulary::builtin("polish").unwrap();
+        assert_eq!(Some(59), calibration("pięćdziewięć", &polish));
+        assert_eq!(Some(73), calibration("siedemośtrzy", &polish));
+        let german = Vocabulary::builtin("german").unwrap();
+        assert_eq!(Some(52), calibration("xfünfzweinsiebenzwei", &german));
+        assert_eq!(Some(71), calibration("siebeneins", &german));
+        assert_eq!(None, Vocabulary::builtin("klingon"));
+    }
+
+    #[test]
+    fn test_prefix_words() {
+        let vocabulary = Vocabulary::parse("# prefixes\nsix 6\nsixt 1\n\nxt 2\n").unwrap();
+        // the longest word starting first, and the longest word ending last
+        assert_eq!(Some(11), calibration("sixt", &vocabulary));
+        assert_eq!(Some(62), calibration("sixxt", &vocabulary));
+        assert_eq!(Some(66), calibration("six", &vocabulary));
+    }
+
+    #[test]
+    fn test_extended_grammar() {
+        let extended = Vocabulary::english_extended();
+        for (line, puzzle, expected) in [
+            ("seventeen", Some(77), 17),
+            ("twenty-one", Some(11), 21),
+            ("xtwentyx", None, 20),
+            ("ninety-nine", Some(99), 99),
+            ("oneleven", Some(11), 11),
+            ("fifteen3", Some(33), 13),
+            ("4fortytwo", Some(42), 42),
+            ("sixty-", Some(66), 60),
+            ("thirteenine", Some(99), 19),
+        ] {
+            assert_eq!(puzzle, calibration(line, &Vocabulary::english()), "{line}");
+            assert_eq!(Some(expected), calibration(line, &extended), "{line}");
+        }
+
+        let line = "xtwenty-one";
+        let [explanation] = explain(&[line.to_string()], &extended).try_into().unwrap();
+        let token = explanation.first.unwrap();
+        assert_eq!(("twenty-one", 21), (token.text(line), token.value));
+        assert_eq!(Some(21), explanation.value());
+
+        let custom = Vocabulary::parse("dozen 12\ngross 144\n").unwrap();
+        assert_eq!(Some(12), calibration("grossdozen", &custom));
+    }
+
+    #[test]
+    fn test_invalid_vocabulary() {
+        assert_eq!(
+            Err("2: expected `word number`".to_string()),
+            Vocabulary::parse("one 1\ntwo\n")
+        );
+        assert_eq!(
+            Err("1: invalid number `x`".to_string()),
+            Vocabulary::parse("one x\n")
+        );
+        assert!(Vocabulary::parse("one 1\none 2\n")
+            .unwrap_err()
+            .contains("duplicate"));
+        assert!(Vocabulary::parse("minus -1\n").is_err());
+        assert!(Vocabulary::parse("4ty 4\n").is_err());
+    }
+}
@@ -0,0 +1,272 @@
+use std::{collections::HashSet, io::BufRead};
+
+use crate::{
+    grid::{Direction, Grid, Point},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    /// The start and the grid with the pipe under it in place of the `S`.
+    type Input = (Point, Grid<char>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve(input.0, &input.1).0)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(enclosed(&solve(input.0, &input.1).1))
+    }
+}
+
+fn solve(start: Point, input: &Grid<char>) -> (i32, Grid<char>) {
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut current = start;
+    while current != start || visited.is_empty() {
+        let (left_neighbour, right_neighbour, top_neighbour, bottom_neighbour) =
+            get_neighbours_not_visited(&current, input, &visited);
+
+        let current_type = input[current];
+        let next = match current_type {
+            '|' => {
+                if matches!(top_neighbour, Some(x) if ['F', '7', '|'].contains(&x)) {
+                    Point {
+                        x: current.x,
+                        y: current.y - 1,
+                    }
+                } else if matches!(bottom_neighbour, Some(x) if ['L', 'J', '|'].contains(&x)) {
+                    Point {
+                        x: current.x,
+                        y: current.y + 1,
+                    }
+                } else {
+                    break;
+                }
+            }
+            '-' => {
+                if matches!(left_neighbour, Some(x) if ['F', 'L', '-'].contains(&x)) {
+                    Point {
+                        x: current.x - 1,
+                        y: current.y,
+                    }
+                } else if matches!(right_neighbour, Some(x) if ['J', '-', '7'].contains(&x)) {
+                    Point {
+                        x: current.x + 1,
+                        y: current.y,
+                    }
+                } else {
+                    break;
+                }
+            }
+            'F' => {
+                if matches!(right_neighbour, Some(x) if ['-', '7', 'J'].contains(&x)) {
+                    Point {
+                        x: current.x + 1,
+                        y: current.y,
+                    }
+                } else if matches!(bottom_neighbour, Some(x) if ['L', 'J', '|'].contains(&x)) {
+                    Point {
+                        x: current.x,
+                        y: current.y + 1,
+                    }
+                } else {
+                    break;
+                }
+            }
+            'J' => {
+                if matches!(top_neighbour, Some(x) if ['|', 'F', '7'].contains(&x)) {
+                    Point {
+                        x: current.x,
+                        y: current.y - 1,
+                    }
+                } else if matches!(left_neighbour, Some(x) if ['L', '-', 'F'].contains(&x)) {
+                    Point {
+                        x: current.x - 1,
+                        y: current.y,
+                    }
+                } else {
+                    break;
+                }
+            }
+            '7' => {
+                if matches!(left_neighbour, Some(x) if ['L', '-', 'F'].contains(&x)) {
+                    Point {
+                        x: current.x - 1,
+                        y: current.y,
+                    }
+                } else if matches!(bottom_neighbour, Some(x) if ['L', 'J', '|'].contains(&x)) {
+                    Point {
+                        x: current.x,
+                        y: current.y + 1,
+                    }
+                } else {
+                    break;
+                }
+            }
+            'L' => {
+                if matches!(right_neighbour, Some(x) if ['-', '7', 'J'].contains(&x)) {
+                    Point {
+                        x: current.x + 1,
+                        y: current.y,
+                    }
+                } else if matches!(top_neighbour, Some(x) if ['|', 'F', '7'].contains(&x)) {
+                    Point {
+                        x: current.x,
+                        y: current.y - 1,
+                    }
+                } else {
+                    break;
+                }
+            }
+            _ => unreachable!("the loop only leads to pipes"),
+        };
+
+        visited.insert(current);
+        current = next;
+    }
+    // the last pipe of the loop has no unvisited way forward
+    visited.insert(current);
+
+    let pipes = Grid::new(
+        input.width(),
+        input.height(),
+        input
+            .iter()
+            .map(|(point, &c)| if visited.contains(&point) { c } else { ' ' })
+            .collect(),
+    );
+    (visited.len() as i32 / 2, pipes)
+}
+
+// Scanline parity: a tile is inside the loop if an odd number of pipes with a northward
+// connection lie to its left.
+fn enclosed(pipes: &Grid<char>) -> i32 {
+    pipes.rows().fold(0, |acc, row| {
+        let mut inside = false;
+        acc + row.iter().fold(0, |acc, c| match c {
+            '|' | 'L' | 'J' => {
+                inside = !inside;
+                acc
+            }
+            ' ' if inside => acc + 1,
+            _ => acc,
+        })
+    })
+}
+
+// left, right, top, bottom
+fn get_neighbours_not_visited(
+    point: &Point,
+    input: &Grid<char>,
+    visited: &HashSet<Point>,
+) -> (Option<char>, Option<char>, Option<char>, Option<char>) {
+    let get_not_visited = |direction| {
+        input
+            .neighbour(*point, direction)
+            .filter(|neighbour| !visited.contains(neighbour))
+            .map(|neighbour| input[neighbour])
+    };
+
+    (
+        get_not_visited(Direction::Left),
+        get_not_visited(Direction::Right),
+        get_not_visited(Direction::Up),
+        get_not_visited(Direction::Down),
+    )
+}
+
+/// The pipe under the start tile, `None` unless exactly two of its neighbours connect to it.
+fn start_pipe(input: &Grid<char>, start: Point) -> Option<char> {
+    let connects = |direction, pipes: &str| {
+        input
+            .neighbour(start, direction)
+            .is_some_and(|neighbour| pipes.contains(input[neighbour]))
+    };
+
+    match (
+        connects(Direction::Left, "-LF"),
+        connects(Direction::Right, "-J7"),
+        connects(Direction::Up, "|7F"),
+        connects(Direction::Down, "|LJ"),
+    ) {
+        (true, true, false, false) => Some('-'),
+        (false, false, true, true) => Some('|'),
+        (false, true, false, true) => Some('F'),
+        (true, false, false, true) => Some('7'),
+        (false, true, true, false) => Some('L'),
+        (true, false, true, false) => Some('J'),
+        _ => None,
+    }
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<(Point, Grid<char>), ParseError> {
+    let mut input = Grid::parse(reader, Day10::DAY, "pipe, ground or start", |c| {
+        "|-LJ7F.S".contains(c).then_some(c)
+    })?;
+
+    let Some(start) = input.position(|&c| c == 'S') else {
+        return Err(ParseError {
+            day: Day10::DAY,
+            line: 1,
+            column: 1,
+            expected: "start tile `S` somewhere in the input".to_string(),
+        });
+    };
+    input[start] = start_pipe(&input, start).ok_or_else(|| ParseError {
+        day: Day10::DAY,
+        line: start.y + 1,
+        column: start.x + 1,
+        expected: "start tile `S` with exactly two pipes connecting to it".to_string(),
+    })?;
+    Ok((start, input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day10>(&[
+            (
+                "S-7\n|X|",
+                "line 2, column 2: expected pipe, ground or start",
+            ),
+            (
+                ".-7",
+                "line 1, column 1: expected start tile `S` somewhere in the input",
+            ),
+            (
+                "S",
+                "line 1, column 1: expected start tile `S` with exactly two pipes connecting to it",
+            ),
+            (
+                ".|.\n-S-\n.|.",
+                "line 2, column 2: expected start tile `S` with exactly two pipes connecting to it",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_start_pipe() {
+        // the start is the bottom left corner of the loop
+        let input = ".....\n.F-7.\n.|.|.\n.S-J.\n.....";
+        let (start, pipes) = Day10::parse(input.as_bytes()).unwrap();
+        assert_eq!((Point::new(1, 3), 'L'), (start, pipes[start]));
+        assert_eq!(Ok(4), Day10::part1(&(start, pipes)));
+
+        let input = ".....\n.F-7.\n.|.|.\n.L-S.\n.....";
+        let (start, pipes) = Day10::parse(input.as_bytes()).unwrap();
+        assert_eq!('J', pipes[start]);
+    }
+}
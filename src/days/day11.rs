@@ -0,0 +1,112 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::{grid::Grid, ParseError, Solution, SolveError};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Universe;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(solve(input, 2))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(solve(input, 1000000))
+    }
+}
+
+fn solve(universe: &Universe, coefficient: usize) -> usize {
+    let galaxies = universe.get_galaxies();
+    let (expanded_rows, expanded_cols) = universe.expanded_places();
+
+    galaxies
+        .iter()
+        .tuple_combinations()
+        .fold(0usize, |acc, (p1, p2)| {
+            acc + (p1.0.min(p2.0)..p1.0.max(p2.0))
+                .map(|r| {
+                    if expanded_rows.contains(&r) {
+                        coefficient
+                    } else {
+                        1
+                    }
+                })
+                .sum::<usize>()
+                + (p1.1.min(p2.1)..p1.1.max(p2.1))
+                    .map(|c| {
+                        if expanded_cols.contains(&c) {
+                            coefficient
+                        } else {
+                            1
+                        }
+                    })
+                    .sum::<usize>()
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct Universe(Grid<char>);
+
+impl Universe {
+    fn expanded_places(&self) -> (Vec<usize>, Vec<usize>) {
+        let rows = self
+            .0
+            .rows()
+            .positions(|row| row.iter().all(|c| *c == '.'))
+            .collect_vec();
+        let cols = self
+            .0
+            .columns()
+            .positions(|mut column| column.all(|c| *c == '.'))
+            .collect_vec();
+
+        (rows, cols)
+    }
+
+    fn get_galaxies(&self) -> Vec<(usize, usize)> {
+        self.0
+            .iter()
+            .filter(|(_, c)| **c == '#')
+            .map(|(point, _)| (point.y, point.x))
+            .collect()
+    }
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Universe, ParseError> {
+    Grid::parse(reader, Day11::DAY, "`.` or `#`", |c| {
+        (c == '.' || c == '#').then_some(c)
+    })
+    .map(Universe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day11>(&[("#.\n.x", "line 2, column 2: expected `.` or `#`")]);
+    }
+
+    #[test]
+    fn test_expansion() {
+        let input = BufReader::new(File::open("inputs/day11/example1.txt").unwrap());
+        let input = read_input(input).unwrap();
+
+        assert_eq!(1030, solve(&input, 10));
+        assert_eq!(8410, solve(&input, 100));
+    }
+}
@@ -0,0 +1,170 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::{
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Err(SolveError::new(Self::DAY, "part 2 is not solved yet"))
+    }
+}
+
+fn part1(input: &[Row]) -> i32 {
+    let n = input
+        .iter()
+        .map(|row| row.records.chars().filter(|&c| c == '?').count())
+        .max()
+        .unwrap()
+        + 1;
+    let characters = [".", "#"];
+
+    // indexed by the number of `?`, rows without any have a single empty combination
+    let mut all_combinations = vec![vec![String::new()], characters.map(String::from).to_vec()];
+    for i in 2..n {
+        let combinations: Vec<_> = (2..i).fold(
+            characters
+                .iter()
+                .cartesian_product(characters.iter())
+                .map(|(&a, &b)| a.to_owned() + b)
+                .collect(),
+            |acc, _| {
+                acc.into_iter()
+                    .cartesian_product(characters.iter())
+                    .map(|(a, b)| a.to_owned() + b)
+                    .collect()
+            },
+        );
+
+        all_combinations.push(combinations);
+    }
+
+    let mut proper_combinations = 0;
+    input.iter().for_each(|row| {
+        let n = row.records.chars().filter(|&c| c == '?').count();
+        let combinations = &all_combinations[n];
+
+        combinations.iter().for_each(|combination| {
+            // replace ? with characters in the combination
+            let mut counter = 0;
+            let s = row
+                .records
+                .chars()
+                .map(|ch| {
+                    if ch == '?' {
+                        counter += 1;
+                        combination.chars().nth(counter - 1).unwrap()
+                    } else {
+                        ch
+                    }
+                })
+                .collect::<String>();
+
+            let grouped = s
+                .split('.')
+                .filter(|&s| !s.is_empty())
+                .map(|s| s.len() as i32)
+                .collect_vec();
+            if grouped == row.arrangements {
+                proper_combinations += 1;
+            }
+        });
+    });
+    proper_combinations
+}
+
+pub struct Row {
+    records: String,
+    arrangements: Vec<i32>,
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<Row>, ParseError> {
+    let rows = lines(reader, Day12::DAY)
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l?;
+            let line = Line::new(Day12::DAY, i, &l);
+            let (records, arrangements) = line.split_once(&l, " ")?;
+            if let Some(x) = records.find(|c| !"?.#".contains(c)) {
+                return Err(line.error(&records[x..], "`?`, `.` or `#`"));
+            }
+            let arrangements = arrangements
+                .split(',')
+                .map(|c| line.parse::<i32>(c, "group size"))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Row {
+                records: records.to_string(),
+                arrangements,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if rows.is_empty() {
+        return Err(ParseError {
+            day: Day12::DAY,
+            line: 1,
+            column: 1,
+            expected: "at least one row of springs".to_string(),
+        });
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day12>(&[
+            ("???.### 1,1,x", "line 1, column 13: expected group size"),
+            ("?x 1", "line 1, column 2: expected `?`, `.` or `#`"),
+            ("???", "line 1, column 1: expected ` `"),
+            ("", "line 1, column 1: expected at least one row of springs"),
+        ]);
+    }
+
+    #[test]
+    fn test_few_unknowns() {
+        for (input, expected) in [
+            ("#.# 1,1", 1),
+            ("#.# 2", 0),
+            ("?.# 1,1", 1),
+            ("#.? 1", 1),
+            ("#.# 1,1\n??? 1", 4),
+        ] {
+            let rows = Day12::parse(input.as_bytes()).unwrap();
+            assert_eq!(Ok(expected), Day12::part1(&rows), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_unsolved_part() {
+        let input = Day12::parse(&b"???.### 1,1,3"[..]).unwrap();
+        assert_eq!(
+            "day 12: part 2 is not solved yet",
+            Day12::part2(&input).unwrap_err().to_string()
+        );
+    }
+}
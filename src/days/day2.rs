@@ -0,0 +1,903 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Write},
+    fs,
+    io::BufRead,
+    path::Path,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    parse::{lines, Line},
+    random::Rng,
+    ParseError, Solution, SolveError,
+};
+
+pub mod format;
+pub mod query;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+}
+
+fn part1(games: &[Game]) -> u64 {
+    possible_ids_sum(games, &CubeSet::puzzle_bag())
+}
+
+fn part2(games: &[Game]) -> Result<u64, SolveError> {
+    power_sum(games, &CubeSet::puzzle_bag()).map_err(|game| power_overflow(games, game))
+}
+
+/// Sum of the ids of the games possible with `bag`.
+pub fn possible_ids_sum(games: &[Game], bag: &CubeSet) -> u64 {
+    games
+        .iter()
+        .filter(|game| game.possible_with(bag))
+        .map(|game| u64::from(game.id))
+        .sum()
+}
+
+/// Sum of the powers of the minimal bags of all games, over the colours of `bag`. Fails with the
+/// index of the first game whose power does not fit into a `u64`, alone or added to the ones
+/// before it.
+pub fn power_sum(games: &[Game], bag: &CubeSet) -> Result<u64, usize> {
+    games.iter().enumerate().try_fold(0u64, |sum, (i, game)| {
+        let power = game.minimal_bag().power(bag.colours());
+        power.and_then(|power| sum.checked_add(power)).ok_or(i)
+    })
+}
+
+/// The error of [`power_sum`] failing at the game with index `game`.
+pub fn power_overflow(games: &[Game], game: usize) -> SolveError {
+    SolveError::new(
+        Day2::DAY,
+        format!(
+            "the sum of powers does not fit into 64 bits at game {}",
+            games[game].id
+        ),
+    )
+}
+
+/// Numbers of cubes by colour, used both for a single draw and for the content of a bag. The
+/// colours keep the order they were given in, but sets with the same cubes are equal in any order.
+#[derive(Debug, Clone, Default)]
+pub struct CubeSet(Vec<(String, u32)>);
+
+impl CubeSet {
+    /// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle_bag() -> Self {
+        CubeSet(
+            [("red", 12), ("green", 13), ("blue", 14)]
+                .map(|(colour, n)| (colour.to_string(), n))
+                .to_vec(),
+        )
+    }
+
+    /// Number of cubes of `colour`, 0 if there are none.
+    pub fn get(&self, colour: &str) -> u32 {
+        self.iter()
+            .find(|&(c, _)| c == colour)
+            .map_or(0, |(_, n)| n)
+    }
+
+    /// Colours with their numbers of cubes, in the order they were given in.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.0.iter().map(|(colour, n)| (colour.as_str(), *n))
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(colour, _)| colour)
+    }
+
+    /// Adds `n` cubes of a new colour, or returns `false` if the colour is already there.
+    fn insert(&mut self, colour: String, n: u32) -> bool {
+        let new = self.get_mut(&colour).is_none();
+        if new {
+            self.0.push((colour, n));
+        }
+        new
+    }
+
+    fn get_mut(&mut self, colour: &str) -> Option<&mut u32> {
+        self.0.iter_mut().find(|(c, _)| c == colour).map(|(_, n)| n)
+    }
+
+    /// Product of the numbers of cubes of `colours`, or `None` if it does not fit into a `u64`.
+    pub fn power<'a>(&self, mut colours: impl Iterator<Item = &'a str>) -> Option<u64> {
+        colours.try_fold(1u64, |power, colour| {
+            power.checked_mul(u64::from(self.get(colour)))
+        })
+    }
+
+    /// Parses draws like `3 blue, 4 red` from the start of `cursor`. With a `bag`, colours must
+    /// be in it.
+    fn parse(cursor: &mut Cursor, bag: Option<&CubeSet>) -> Result<Self, ParseError> {
+        let mut set = CubeSet::default();
+        loop {
+            let num = cursor.number("number of cubes")?;
+            cursor.literal(" ")?;
+            let colour = cursor.peek_word();
+            match bag {
+                Some(bag) if !bag.colours().any(|c| c == colour) => {
+                    return Err(cursor.error(bag.expected_colours()))
+                }
+                None if colour.is_empty() => return Err(cursor.error("colour name")),
+                _ => {}
+            }
+            if !set.insert(colour.to_string(), num) {
+                return Err(cursor.error("colour not drawn before"));
+            }
+            cursor.advance(colour.len());
+
+            if !cursor.eat(", ") {
+                return Ok(set);
+            }
+        }
+    }
+
+    /// The colours of the bag in alphabetical order, like "`blue`, `green` or `red`".
+    fn expected_colours(&self) -> String {
+        let mut colours = self
+            .colours()
+            .sorted()
+            .map(|colour| format!("`{colour}`"))
+            .collect_vec();
+        let last = colours.pop().unwrap_or_else(|| "no colours".to_string());
+        match colours.is_empty() {
+            true => last,
+            false => format!("{} or {last}", colours.join(", ")),
+        }
+    }
+
+    /// Reads a bag from lines in the syntax of a draw, like `12 red, 13 green`. Empty lines and
+    /// lines starting with `#` are ignored.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let mut bag = CubeSet::default();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            let set = line
+                .parse::<CubeSet>()
+                .map_err(|e| format!("{}:{}: {e}", path.display(), i + 1))?;
+            for (colour, n) in set.0 {
+                if !bag.insert(colour.clone(), n) {
+                    return Err(format!(
+                        "{}:{}: `{colour}` is given twice",
+                        path.display(),
+                        i + 1
+                    ));
+                }
+            }
+        }
+        Ok(bag)
+    }
+}
+
+impl PartialEq for CubeSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.iter().all(|(colour, n)| other.get(colour) == n)
+    }
+}
+
+impl Eq for CubeSet {}
+
+/// Prints the syntax of a draw, like `3 blue, 4 red`.
+impl Display for CubeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let draws = self.iter().map(|(colour, n)| format!("{n} {colour}"));
+        write!(f, "{}", draws.format(", "))
+    }
+}
+
+impl FromStr for CubeSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(Day2::DAY, 0, s);
+        let mut cursor = Cursor::new(&line);
+        CubeSet::parse(&mut cursor, None)
+            .and_then(|set| cursor.end("`, ` or the end").map(|_| set))
+            .map_err(|e| format!("column {}: expected {}", e.column, e.expected))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub sets: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Parses a line like `Game 1: 3 blue, 4 red; 1 red`. Errors in a set name the set, numbered
+    /// from 1, and the game.
+    fn parse(line: &Line, bag: &CubeSet) -> Result<Self, ParseError> {
+        let mut cursor = Cursor::new(line);
+        cursor.literal("Game ")?;
+        let id = cursor.number("game id")?;
+        cursor.literal(": ")?;
+
+        let mut sets = Vec::new();
+        loop {
+            let index = sets.len() + 1;
+            let in_set = |mut error: ParseError| {
+                error.expected += &format!(" in set {index} of game {id}");
+                error
+            };
+            let set = CubeSet::parse(&mut cursor, Some(bag)).map_err(in_set)?;
+            sets.push(set);
+            if !cursor.eat("; ") {
+                cursor
+                    .end("`, `, `; ` or the end of the line")
+                    .map_err(in_set)?;
+                return Ok(Game { id, sets });
+            }
+        }
+    }
+
+    /// Whether every draw fits into `bag`.
+    pub fn possible_with(&self, bag: &CubeSet) -> bool {
+        self.violations(bag).next().is_none()
+    }
+
+    /// The draws with more cubes of a colour than `bag` holds, in the order of the log.
+    pub fn violations<'a>(&'a self, bag: &'a CubeSet) -> impl Iterator<Item = Violation<'a>> {
+        self.sets.iter().enumerate().flat_map(move |(i, set)| {
+            set.iter()
+                .map(move |(colour, drawn)| Violation {
+                    set: i + 1,
+                    colour,
+                    drawn,
+                    limit: bag.get(colour),
+                })
+                .filter(|v| v.drawn > v.limit)
+        })
+    }
+
+    /// The fewest cubes of each colour that make all draws possible.
+    pub fn minimal_bag(&self) -> CubeSet {
+        let mut bag = CubeSet::default();
+        for (colour, n) in self.sets.iter().flat_map(CubeSet::iter) {
+            match bag.get_mut(colour) {
+                Some(max) => *max = (*max).max(n),
+                None => bag.0.push((colour.to_string(), n)),
+            }
+        }
+        bag
+    }
+}
+
+/// A draw that makes a game impossible: `drawn` cubes of `colour` in set `set`, numbered from 1,
+/// when the bag holds only `limit`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation<'a> {
+    pub set: usize,
+    pub colour: &'a str,
+    pub drawn: u32,
+    pub limit: u32,
+}
+
+/// Half the 95% quantile of the chi-squared distribution with one degree of freedom: how far the
+/// log-likelihood may drop inside a 95% profile likelihood interval.
+const LIKELIHOOD_DROP_95: f64 = 3.841_458_820_694_124 / 2.0;
+
+/// The most likely bag behind a log of games, see [`estimate_bag`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    /// The observed colours, in alphabetical order.
+    pub colours: Vec<ColourEstimate>,
+    /// Natural logarithm of the probability of all logged sets with the estimated bag.
+    pub log_likelihood: f64,
+    /// Whether the estimate holds the limit of cubes, so that bigger bags may be more likely.
+    pub limited: bool,
+}
+
+/// Estimated number of cubes of a colour, with its 95% confidence interval `lower..=upper`.
+/// `upper` is `None` if the interval reaches the limit of cubes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColourEstimate {
+    pub colour: String,
+    pub count: u32,
+    pub lower: u32,
+    pub upper: Option<u32>,
+}
+
+impl Estimate {
+    pub fn bag(&self) -> CubeSet {
+        CubeSet(
+            self.colours
+                .iter()
+                .map(|estimate| (estimate.colour.clone(), estimate.count))
+                .collect(),
+        )
+    }
+}
+
+/// The most cubes a bag may hold in [`estimate_bag`], whose table of logarithms grows with the
+/// limit: 8 MiB at this size.
+pub const MAX_BAG: u32 = 1 << 20;
+
+/// Maximum-likelihood estimate of the bag behind `games`, holding at most `limit` cubes (raised to
+/// the size of the minimal bag if needed). Fails if that is more than [`MAX_BAG`].
+///
+/// Every set is a draw without replacement from the full bag, so its probability is
+/// multivariate hypergeometric: the product of `C(bag[colour], set[colour])` over the colours,
+/// divided by `C(bag size, set size)`. The estimate is found by hill climbing from the minimal
+/// bag, and the bounds of a colour are where its profile likelihood, maximised over the other
+/// colours, drops out of the 95% confidence region.
+pub fn estimate_bag(games: &[Game], limit: u32) -> Result<Estimate, SolveError> {
+    let model = Model::new(games, limit)?;
+    let mut counts = model.minimal.clone();
+    let log_likelihood = model.climb(&mut counts, None);
+    let threshold = log_likelihood - LIKELIHOOD_DROP_95;
+
+    let colours = model
+        .colours
+        .iter()
+        .enumerate()
+        .map(|(colour, name)| ColourEstimate {
+            colour: name.clone(),
+            count: counts[colour],
+            lower: model
+                .bound(&counts, colour, -1, threshold)
+                .expect("the minimal bag is within the limit"),
+            upper: model.bound(&counts, colour, 1, threshold),
+        })
+        .collect();
+    Ok(Estimate {
+        colours,
+        log_likelihood,
+        limited: counts.iter().sum::<u32>() == model.limit,
+    })
+}
+
+/// The log in a form that makes its likelihood cheap to evaluate for any bag.
+struct Model {
+    colours: Vec<String>,
+    minimal: Vec<u32>,
+    /// For every colour, how many sets drew each number of its cubes, if above 0.
+    drawn: Vec<Vec<(u32, u32)>>,
+    /// How many sets drew each number of cubes in total.
+    sizes: Vec<(u32, u32)>,
+    limit: u32,
+    ln_factorials: Vec<f64>,
+}
+
+impl Model {
+    fn new(games: &[Game], limit: u32) -> Result<Self, SolveError> {
+        let sets = || games.iter().flat_map(|game| &game.sets);
+        let mut drawn = BTreeMap::<&str, BTreeMap<u32, u32>>::new();
+        for set in sets() {
+            for (colour, n) in set.iter() {
+                let histogram = drawn.entry(colour).or_default();
+                if n > 0 {
+                    *histogram.entry(n).or_default() += 1;
+                }
+            }
+        }
+
+        let minimal = drawn
+            .values()
+            .map(|histogram| histogram.keys().last().copied().unwrap_or(0))
+            .collect_vec();
+        let minimal_size = minimal.iter().map(|&n| u64::from(n)).sum::<u64>();
+        let limit = u64::from(limit).max(minimal_size);
+        if limit > u64::from(MAX_BAG) {
+            return Err(SolveError::new(
+                Day2::DAY,
+                format!("a bag of {limit} cubes is more than the {MAX_BAG} that can be estimated"),
+            ));
+        }
+        let limit = limit as u32;
+
+        // no set draws more than the minimal bag holds, so its size fits
+        let mut sizes = BTreeMap::<u32, u32>::new();
+        for set in sets() {
+            *sizes.entry(set.iter().map(|(_, n)| n).sum()).or_default() += 1;
+        }
+        let mut ln_factorials = vec![0.0; limit as usize + 1];
+        for n in 1..ln_factorials.len() {
+            ln_factorials[n] = ln_factorials[n - 1] + (n as f64).ln();
+        }
+        Ok(Model {
+            colours: drawn.keys().map(|colour| colour.to_string()).collect(),
+            minimal,
+            drawn: drawn
+                .into_values()
+                .map(|histogram| histogram.into_iter().collect())
+                .collect(),
+            sizes: sizes.into_iter().collect(),
+            limit,
+            ln_factorials,
+        })
+    }
+
+    fn ln_binomial(&self, n: u32, k: u32) -> f64 {
+        let f = |n: u32| self.ln_factorials[n as usize];
+        f(n) - f(k) - f(n - k)
+    }
+
+    fn log_likelihood(&self, counts: &[u32]) -> f64 {
+        let total = counts.iter().sum();
+        let drawn = counts
+            .iter()
+            .zip(&self.drawn)
+            .flat_map(|(&n, histogram)| {
+                histogram
+                    .iter()
+                    .map(move |&(k, sets)| f64::from(sets) * self.ln_binomial(n, k))
+            })
+            .sum::<f64>();
+        let sizes = self
+            .sizes
+            .iter()
+            .map(|&(k, sets)| f64::from(sets) * self.ln_binomial(total, k))
+            .sum::<f64>();
+        drawn - sizes
+    }
+
+    fn valid(&self, counts: &[u32]) -> bool {
+        counts.iter().zip(&self.minimal).all(|(n, min)| n >= min)
+            && counts.iter().map(|&n| u64::from(n)).sum::<u64>() <= u64::from(self.limit)
+    }
+
+    /// Moves `counts` uphill, leaving the `fixed` colour alone, in steps of one colour or of all
+    /// free colours in proportion. Steps start big and halve when none of them helps any more.
+    /// Returns the log-likelihood reached.
+    fn climb(&self, counts: &mut [u32], fixed: Option<usize>) -> f64 {
+        let free = (0..counts.len())
+            .filter(|&c| Some(c) != fixed)
+            .collect_vec();
+        let mut best = self.log_likelihood(counts);
+        let mut step = self.limit.next_power_of_two();
+        while step > 0 {
+            let mut candidates = Vec::new();
+            for &c in &free {
+                for n in [counts[c].checked_sub(step), counts[c].checked_add(step)] {
+                    let mut candidate = counts.to_vec();
+                    candidate[c] = n.unwrap_or(0);
+                    candidates.push(candidate);
+                }
+            }
+            let free_total = free.iter().map(|&c| counts[c]).sum::<u32>();
+            for target in [free_total.saturating_sub(step), free_total + step] {
+                let mut candidate = counts.to_vec();
+                for &c in &free {
+                    let scaled = f64::from(counts[c]) * f64::from(target) / f64::from(free_total);
+                    candidate[c] = (scaled.round() as u32).max(self.minimal[c]);
+                }
+                candidates.push(candidate);
+            }
+
+            let better = candidates
+                .into_iter()
+                .filter(|candidate| self.valid(candidate))
+                .map(|candidate| (self.log_likelihood(&candidate), candidate))
+                .filter(|(log_likelihood, _)| *log_likelihood > best + 1e-9)
+                .max_by(|(a, _), (b, _)| a.total_cmp(b));
+            match better {
+                Some((log_likelihood, candidate)) => {
+                    best = log_likelihood;
+                    counts.copy_from_slice(&candidate);
+                }
+                None => step /= 2,
+            }
+        }
+        best
+    }
+
+    /// The last number of cubes of `colour`, going from the estimate `counts` in `direction`,
+    /// whose profile log-likelihood stays above `threshold`, or `None` at the limit of cubes.
+    fn bound(&self, counts: &[u32], colour: usize, direction: i32, threshold: f64) -> Option<u32> {
+        let mut profile = counts.to_vec();
+        let mut last = counts[colour];
+        loop {
+            let Some(next) = last.checked_add_signed(direction) else {
+                return Some(last);
+            };
+            if next < self.minimal[colour] {
+                return Some(last);
+            }
+            profile[colour] = next;
+            if !self.valid(&profile) {
+                // make room by starting the other colours from their minimum
+                for (c, n) in profile.iter_mut().enumerate() {
+                    if c != colour {
+                        *n = self.minimal[c];
+                    }
+                }
+                if !self.valid(&profile) {
+                    return None;
+                }
+            }
+            if self.climb(&mut profile, Some(colour)) < threshold {
+                return Some(last);
+            }
+            last = next;
+        }
+    }
+}
+
+/// Prints the line of the game in the puzzle's syntax, like `Game 1: 3 blue, 4 red; 1 red`.
+impl Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: {}", self.id, self.sets.iter().format("; "))
+    }
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<Game>, ParseError> {
+    read_games(reader, &CubeSet::puzzle_bag())
+}
+
+/// Parses game records, rejecting colours that are not in `bag`.
+pub fn read_games<R: BufRead>(reader: R, bag: &CubeSet) -> Result<Vec<Game>, ParseError> {
+    lines(reader, Day2::DAY)
+        .enumerate()
+        .map(|(i, l)| Game::parse(&Line::new(Day2::DAY, i, &l?), bag))
+        .collect()
+}
+
+/// Generates a log of `games` random games possible with `bag`, in the puzzle's syntax. Colours
+/// without cubes in the bag are never drawn.
+///
+/// # Panics
+///
+/// If no colour of `bag` has any cubes.
+pub fn generate(games: usize, bag: &CubeSet, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let colours = bag.iter().filter(|&(_, n)| n > 0).collect_vec();
+    assert!(!colours.is_empty(), "no cubes in the bag `{bag}`");
+    let mut log = String::new();
+    for id in 1..=games {
+        write!(log, "Game {id}: ").unwrap();
+        let sets = 1 + rng.below(6);
+        for set in 0..sets {
+            if set > 0 {
+                log += "; ";
+            }
+            let mut draws = Vec::new();
+            for (colour, limit) in &colours {
+                if rng.below(3) > 0 {
+                    draws.push((*colour, 1 + rng.below(u64::from(*limit))));
+                }
+            }
+            if draws.is_empty() {
+                draws.push((colours[0].0, 1));
+            }
+            let draws = draws.iter().map(|(colour, n)| format!("{n} {colour}"));
+            log += &draws.format(", ").to_string();
+        }
+        log.push('\n');
+    }
+    log
+}
+
+/// Reads a line from left to right in a single pass, without splitting it first.
+struct Cursor<'a, 'l> {
+    line: &'l Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a, 'l> Cursor<'a, 'l> {
+    fn new(line: &'l Line<'a>) -> Self {
+        Cursor {
+            line,
+            rest: line.text(),
+        }
+    }
+
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.line.error(self.rest, expected)
+    }
+
+    fn advance(&mut self, len: usize) {
+        self.rest = &self.rest[len..];
+    }
+
+    /// Skips `literal` if the rest starts with it.
+    fn eat(&mut self, literal: &str) -> bool {
+        let starts = self.rest.starts_with(literal);
+        if starts {
+            self.advance(literal.len());
+        }
+        starts
+    }
+
+    fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        match self.eat(literal) {
+            true => Ok(()),
+            false => Err(self.error(format!("`{literal}`"))),
+        }
+    }
+
+    fn number(&mut self, expected: &str) -> Result<u32, ParseError> {
+        let len = self.rest.bytes().take_while(u8::is_ascii_digit).count();
+        let number = self.line.parse(&self.rest[..len], expected)?;
+        self.advance(len);
+        Ok(number)
+    }
+
+    /// The lowercase letters at the start of the rest, without skipping them.
+    fn peek_word(&self) -> &'a str {
+        let len = self.rest.bytes().take_while(u8::is_ascii_lowercase).count();
+        &self.rest[..len]
+    }
+
+    fn end(&self, expected: &str) -> Result<(), ParseError> {
+        match self.rest.is_empty() {
+            true => Ok(()),
+            false => Err(self.error(expected)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use super::*;
+
+    #[test]
+    fn test_invalid_colour() {
+        let input = &b"Game 1: 3 blue, 4 red\nGame 2: 3 blue, 4 brown"[..];
+        let error = read_input(input).err().unwrap();
+        assert_eq!((2, 19), (error.line, error.column));
+        assert_eq!(
+            "`blue`, `green` or `red` in set 1 of game 2",
+            error.expected
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        let bag = CubeSet::puzzle_bag();
+        let log = generate(100, &bag, 1);
+        let games = read_input(log.as_bytes()).unwrap();
+        assert_eq!(100, games.len());
+        assert!(games.iter().all(|game| game.possible_with(&bag)));
+        assert_eq!(log, generate(100, &bag, 1));
+
+        let bag = "0 red, 2 blue".parse::<CubeSet>().unwrap();
+        let games = read_games(generate(20, &bag, 2).as_bytes(), &bag).unwrap();
+        assert!(games.iter().all(|game| game.minimal_bag().get("red") == 0));
+        assert!(games.iter().all(|game| game.possible_with(&bag)));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        for (line, column, expected) in [
+            ("Game x: 1 red", 6, "game id"),
+            ("Game 1 1 red", 7, "`: `"),
+            (
+                "Game 3: 1 red; 2 green,1 blue",
+                23,
+                "`, `, `; ` or the end of the line in set 2 of game 3",
+            ),
+            (
+                "Game 3: 1 red; red",
+                16,
+                "number of cubes in set 2 of game 3",
+            ),
+            (
+                "Game 3: 1 red;",
+                14,
+                "`, `, `; ` or the end of the line in set 1 of game 3",
+            ),
+            (
+                "Game 4: 1 red, 2 red",
+                18,
+                "colour not drawn before in set 1 of game 4",
+            ),
+        ] {
+            let error = read_input(line.as_bytes()).unwrap_err();
+            assert_eq!(
+                (1, column, expected),
+                (error.line, error.column, error.expected.as_str()),
+                "{line}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_bag() {
+        let bag = "2 red, 1 yellow".parse::<CubeSet>().unwrap();
+        assert_eq!(
+            (2, 1, 0),
+            (bag.get("red"), bag.get("yellow"), bag.get("blue"))
+        );
+        let input =
+            &b"Game 1: 1 yellow, 2 red\nGame 2: 3 red\nGame 3: 1 yellow; 1 yellow, 1 red"[..];
+        let games = read_games(input, &bag).unwrap();
+        assert_eq!(4, possible_ids_sum(&games, &bag));
+        // game 2 has no yellow cubes, so its power is 0
+        assert_eq!(Ok(2 + 1), power_sum(&games, &bag));
+        assert_eq!(2, games[0].minimal_bag().get("red"));
+
+        let error = read_games(&b"Game 1: 1 blue"[..], &bag).unwrap_err();
+        assert_eq!(
+            (1, 11, "`red` or `yellow` in set 1 of game 1"),
+            (error.line, error.column, error.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let bag = "100 a, 100 b, 100 c, 100 d, 100 e"
+            .parse::<CubeSet>()
+            .unwrap();
+        assert_eq!(Some(10_000_000_000), bag.power(bag.colours()));
+
+        let games =
+            Day2::parse(&b"Game 1: 1 red\nGame 2: 5000000 red, 5000000 green, 5000000 blue"[..])
+                .unwrap();
+        assert_eq!(
+            None,
+            games[1]
+                .minimal_bag()
+                .power(["red", "green", "blue"].into_iter())
+        );
+        assert_eq!(
+            Err("day 2: the sum of powers does not fit into 64 bits at game 2".to_string()),
+            Day2::part2(&games).map_err(|e| e.to_string())
+        );
+
+        // each power fits, their sum does not
+        let game = "2147483648 red, 2147483648 green, 2 blue";
+        let games = Day2::parse(format!("Game 1: {game}\nGame 2: {game}").as_bytes()).unwrap();
+        assert_eq!(
+            Some(1 << 63),
+            games[0]
+                .minimal_bag()
+                .power(["red", "green", "blue"].into_iter())
+        );
+        assert_eq!(Err(1), power_sum(&games, &CubeSet::puzzle_bag()));
+    }
+
+    #[test]
+    fn test_violations() {
+        let bag = CubeSet::puzzle_bag();
+        let input = BufReader::new(File::open("inputs/day2/example1.txt").unwrap());
+        let games = read_input(input).unwrap();
+        let violations = |i: usize| games[i].violations(&bag).collect_vec();
+        assert!(violations(0).is_empty());
+        assert_eq!(
+            vec![Violation {
+                set: 1,
+                colour: "red",
+                drawn: 20,
+                limit: 12
+            }],
+            violations(2)
+        );
+        assert_eq!(
+            vec![(3, "blue", 15), (3, "red", 14)],
+            violations(3)
+                .iter()
+                .map(|v| (v.set, v.colour, v.drawn))
+                .collect_vec()
+        );
+        assert_eq!(
+            vec![false, false, true, true, false],
+            games
+                .iter()
+                .map(|g| g.violations(&bag).next().is_some())
+                .collect_vec()
+        );
+    }
+
+    /// A log of `sets` random draws without replacement of up to `size` cubes from `bag`, one
+    /// set per game.
+    fn sample(bag: &CubeSet, sets: usize, size: u64, seed: u64) -> Vec<Game> {
+        let mut rng = Rng::new(seed);
+        let cubes = bag
+            .iter()
+            .flat_map(|(colour, n)| std::iter::repeat_n(colour, n as usize))
+            .collect_vec();
+        (1..=sets)
+            .map(|id| {
+                let mut cubes = cubes.clone();
+                let mut set = CubeSet::default();
+                for i in 0..1 + rng.below(size) as usize {
+                    let j = i + rng.below((cubes.len() - i) as u64) as usize;
+                    cubes.swap(i, j);
+                    match set.get_mut(cubes[i]) {
+                        Some(n) => *n += 1,
+                        None => set.0.push((cubes[i].to_string(), 1)),
+                    }
+                }
+                Game {
+                    id: id as u32,
+                    sets: vec![set],
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_estimate_bag() {
+        let bag = CubeSet::puzzle_bag();
+        let estimate = estimate_bag(&sample(&bag, 3000, 20, 7), 500).unwrap();
+        assert!(!estimate.limited);
+        assert_eq!(
+            vec!["blue", "green", "red"],
+            estimate.colours.iter().map(|c| &c.colour).collect_vec()
+        );
+        for c in &estimate.colours {
+            let truth = bag.get(&c.colour);
+            assert!(
+                c.lower <= truth && c.upper.is_some_and(|upper| truth <= upper),
+                "{c:?}"
+            );
+            assert!(c.lower <= c.count && c.upper >= Some(c.count));
+        }
+    }
+
+    #[test]
+    fn test_estimate_small_log() {
+        // the only draw is certain with one cube of each colour, and likely enough with up to
+        // 92 of one colour, when the other colour gets the remaining 8 of the limit
+        let games = read_input(&b"Game 1: 1 red, 1 blue"[..]).unwrap();
+        let estimate = estimate_bag(&games, 100).unwrap();
+        assert_eq!("1 blue, 1 red".parse(), Ok(estimate.bag()));
+        assert_eq!(0.0, estimate.log_likelihood);
+        assert!(!estimate.limited);
+        assert!(estimate
+            .colours
+            .iter()
+            .all(|c| (c.lower, c.upper) == (1, Some(92))));
+
+        // the more cubes the same colour drawn in separate sets, the bigger the bag
+        let games = read_input(&b"Game 1: 3 red\nGame 2: 3 blue"[..]).unwrap();
+        let estimate = estimate_bag(&games, 10).unwrap();
+        assert!(estimate.limited);
+        assert_eq!("5 blue, 5 red".parse(), Ok(estimate.bag()));
+        assert!(estimate
+            .colours
+            .iter()
+            .all(|c| (c.lower, c.upper) == (3, None)));
+    }
+
+    #[test]
+    fn test_estimate_huge_bag() {
+        let games = read_input(&b"Game 1: 4000000000 red, 4000000000 blue"[..]).unwrap();
+        assert_eq!(
+            Err(format!(
+                "day 2: a bag of 8000000000 cubes is more than the {MAX_BAG} that can be estimated"
+            )),
+            estimate_bag(&games, 10).map_err(|e| e.to_string())
+        );
+        let games = read_input(&b"Game 1: 1 red"[..]).unwrap();
+        assert!(estimate_bag(&games, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_invalid_bag() {
+        assert_eq!(
+            Err("column 10: expected colour not drawn before".to_string()),
+            "1 red, 2 red".parse::<CubeSet>()
+        );
+        assert!("12 Red".parse::<CubeSet>().is_err());
+    }
+}
@@ -0,0 +1,319 @@
+use std::{fmt::Write, io::BufRead, str::FromStr};
+
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+use super::{read_games, CubeSet, Day2, Game};
+use crate::{
+    parse::{lines, Line},
+    ParseError, Solution,
+};
+
+/// Formats a log of games can be written in and read back from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// The puzzle's syntax, one game per line. Printing a parsed log gives back the same text.
+    Text,
+    /// A header `game,set,` followed by the colours, then one row per draw with the game id, the
+    /// number of the set from 1 and the numbers of cubes, empty for colours not drawn.
+    Csv,
+    /// An array of objects like `{"id": 1, "sets": [{"blue": 3, "red": 4}]}`.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(LogFormat::Text),
+            "csv" => Ok(LogFormat::Csv),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!(
+                "invalid log format `{s}`, expected `text`, `csv` or `json`"
+            )),
+        }
+    }
+}
+
+/// Writes `games` in `format`. CSV and JSON order the colours of each draw by their columns or
+/// alphabetically, so reading them back gives equal games, though maybe not the same text.
+pub fn write_log(games: &[Game], format: LogFormat) -> String {
+    match format {
+        LogFormat::Text => games.iter().map(|game| format!("{game}\n")).collect(),
+        LogFormat::Csv => to_csv(games),
+        LogFormat::Json => to_json(games),
+    }
+}
+
+/// Reads games in `format`, rejecting colours that are not in `bag`.
+pub fn read_log(text: &str, bag: &CubeSet, format: LogFormat) -> Result<Vec<Game>, String> {
+    match format {
+        LogFormat::Text => read_games(text.as_bytes(), bag).map_err(|e| e.to_string()),
+        LogFormat::Csv => read_csv(text.as_bytes(), bag).map_err(|e| e.to_string()),
+        LogFormat::Json => from_json(text, bag),
+    }
+}
+
+/// The colour columns are in the order the colours first appear in the log.
+pub fn to_csv(games: &[Game]) -> String {
+    let colours = games
+        .iter()
+        .flat_map(|game| &game.sets)
+        .flat_map(CubeSet::colours)
+        .unique()
+        .collect_vec();
+    let mut csv = format!(
+        "game,set{}\n",
+        colours.iter().map(|c| format!(",{c}")).join("")
+    );
+    for game in games {
+        for (i, set) in game.sets.iter().enumerate() {
+            write!(csv, "{},{}", game.id, i + 1).unwrap();
+            for &colour in &colours {
+                csv.push(',');
+                if let Some((_, n)) = set.iter().find(|&(c, _)| c == colour) {
+                    write!(csv, "{n}").unwrap();
+                }
+            }
+            csv.push('\n');
+        }
+    }
+    csv
+}
+
+/// Reads the rows written by [`to_csv`]. The sets of a game must follow each other, numbered
+/// from 1.
+pub fn read_csv<R: BufRead>(reader: R, bag: &CubeSet) -> Result<Vec<Game>, ParseError> {
+    let mut lines = lines(reader, Day2::DAY);
+    let Some(header) = lines.next().transpose()? else {
+        return Ok(Vec::new());
+    };
+    let header = header.strip_suffix('\r').unwrap_or(&header);
+    let line = Line::new(Day2::DAY, 0, header);
+    let mut cells = header.split(',');
+    for column in ["game", "set"] {
+        match cells.next() {
+            Some(cell) if cell == column => {}
+            Some(cell) => return Err(line.error(cell, format!("`{column}`"))),
+            None => return Err(line.error_at_end(format!("`,{column}`"))),
+        }
+    }
+    let mut colours = CubeSet::default();
+    for cell in cells {
+        if !bag.colours().any(|colour| colour == cell) {
+            return Err(line.error(cell, bag.expected_colours()));
+        }
+        if !colours.insert(cell.to_string(), 0) {
+            return Err(line.error(cell, "colour not given before"));
+        }
+    }
+
+    let mut games: Vec<Game> = Vec::new();
+    for (i, row) in lines.enumerate() {
+        let row = row?;
+        let row = row.strip_suffix('\r').unwrap_or(&row);
+        let line = Line::new(Day2::DAY, i + 1, row);
+        let cells = row.split(',').collect_vec();
+        let columns = colours.0.len() + 2;
+        if cells.len() < columns {
+            return Err(line.error_at_end("`,`"));
+        } else if cells.len() > columns {
+            return Err(line.error(cells[columns], "the end of the row"));
+        }
+
+        let id = line.parse(cells[0], "game id")?;
+        let index = line.parse::<usize>(cells[1], "set number")?;
+        let mut set = CubeSet::default();
+        for (colour, cell) in colours.colours().zip(&cells[2..]) {
+            if !cell.is_empty() {
+                set.insert(colour.to_string(), line.parse(cell, "number of cubes")?);
+            }
+        }
+        if set.0.is_empty() {
+            return Err(line.error(cells.get(2).unwrap_or(&""), "at least one number of cubes"));
+        }
+
+        match games.last_mut() {
+            Some(game) if game.id == id && index == game.sets.len() + 1 => game.sets.push(set),
+            _ if index == 1 => games.push(Game {
+                id,
+                sets: vec![set],
+            }),
+            Some(game) if game.id == id => {
+                let next = game.sets.len() + 1;
+                return Err(line.error(cells[1], format!("set 1 or {next}")));
+            }
+            _ => return Err(line.error(cells[1], "set 1 of a new game")),
+        }
+    }
+    Ok(games)
+}
+
+pub fn to_json(games: &[Game]) -> String {
+    let games = games
+        .iter()
+        .map(|game| {
+            let sets = game
+                .sets
+                .iter()
+                .map(|set| {
+                    set.iter()
+                        .map(|(colour, n)| (colour.to_string(), json!(n)))
+                        .collect::<serde_json::Map<_, _>>()
+                })
+                .collect_vec();
+            json!({ "id": game.id, "sets": sets })
+        })
+        .collect_vec();
+    serde_json::to_string_pretty(&games).unwrap() + "\n"
+}
+
+/// Reads the games written by [`to_json`]. Errors point at the offending value, like
+/// "[2].sets[0]: expected a number of `red` cubes".
+pub fn from_json(text: &str, bag: &CubeSet) -> Result<Vec<Game>, String> {
+    let value = serde_json::from_str::<Value>(text).map_err(|e| e.to_string())?;
+    let games = value.as_array().ok_or("expected an array of games")?;
+    let number = |value: &Value| value.as_u64().and_then(|n| u32::try_from(n).ok());
+    games
+        .iter()
+        .enumerate()
+        .map(|(i, game)| {
+            let error = |expected: &str| format!("[{i}]: expected {expected}");
+            let id = game
+                .get("id")
+                .and_then(number)
+                .ok_or_else(|| error("an `id` that fits into 32 bits"))?;
+            let sets = game
+                .get("sets")
+                .and_then(Value::as_array)
+                .filter(|sets| !sets.is_empty())
+                .ok_or_else(|| error("a non-empty array of `sets`"))?;
+
+            let sets = sets
+                .iter()
+                .enumerate()
+                .map(|(j, cubes)| {
+                    let error = |expected: &str| format!("[{i}].sets[{j}]: expected {expected}");
+                    let cubes = cubes
+                        .as_object()
+                        .filter(|cubes| !cubes.is_empty())
+                        .ok_or_else(|| error("an object with numbers of cubes by colour"))?;
+                    let mut set = CubeSet::default();
+                    for (colour, n) in cubes {
+                        if !bag.colours().any(|c| c == colour) {
+                            return Err(error(&bag.expected_colours()));
+                        }
+                        let n = number(n)
+                            .ok_or_else(|| error(&format!("a number of `{colour}` cubes")))?;
+                        set.insert(colour.clone(), n);
+                    }
+                    Ok(set)
+                })
+                .collect::<Result<_, String>>()?;
+            Ok(Game { id, sets })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day2::{generate, part1, part2};
+
+    fn example() -> String {
+        std::fs::read_to_string("inputs/day2/example1.txt").unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let bag = CubeSet::puzzle_bag();
+        for log in [example(), generate(50, &bag, 3)] {
+            let games = read_log(&log, &bag, LogFormat::Text).unwrap();
+            assert_eq!(log, write_log(&games, LogFormat::Text));
+            for format in [LogFormat::Csv, LogFormat::Json] {
+                let written = write_log(&games, format);
+                let read = read_log(&written, &bag, format).unwrap();
+                assert_eq!(games, read, "{format:?}");
+                assert_eq!((part1(&games), part2(&games)), (part1(&read), part2(&read)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let games = read_log(&example(), &CubeSet::puzzle_bag(), LogFormat::Text).unwrap();
+        let csv = to_csv(&games[..1]);
+        assert_eq!(
+            "game,set,blue,red,green\n1,1,3,4,\n1,2,6,1,2\n1,3,,,2\n",
+            csv
+        );
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let bag = CubeSet::puzzle_bag();
+        for (csv, error) in [
+            ("game,red", "line 1, column 6: expected `set`"),
+            (
+                "game,set,pink",
+                "line 1, column 10: expected `blue`, `green` or `red`",
+            ),
+            (
+                "game,set,red,red",
+                "line 1, column 14: expected colour not given before",
+            ),
+            ("game,set,red\n1,1", "line 2, column 4: expected `,`"),
+            (
+                "game,set,red\n1,1,2,3",
+                "line 2, column 7: expected the end of the row",
+            ),
+            (
+                "game,set,red\n1,1,x",
+                "line 2, column 5: expected number of cubes",
+            ),
+            (
+                "game,set,red\n1,1,",
+                "line 2, column 5: expected at least one number of cubes",
+            ),
+            (
+                "game,set,red\n1,2,1",
+                "line 2, column 3: expected set 1 of a new game",
+            ),
+            (
+                "game,set,red\n1,1,1\n1,3,1",
+                "line 3, column 3: expected set 1 or 2",
+            ),
+        ] {
+            let result = read_csv(csv.as_bytes(), &bag).map_err(|e| e.to_string());
+            assert_eq!(Err(format!("day 2, {error}")), result, "{csv}");
+        }
+    }
+
+    #[test]
+    fn test_json_errors() {
+        let bag = CubeSet::puzzle_bag();
+        for (json, error) in [
+            ("{}", "expected an array of games"),
+            (
+                r#"[{"sets": []}]"#,
+                "[0]: expected an `id` that fits into 32 bits",
+            ),
+            (
+                r#"[{"id": 1, "sets": []}]"#,
+                "[0]: expected a non-empty array of `sets`",
+            ),
+            (
+                r#"[{"id": 1, "sets": [{"red": 1}, {"red": -1}]}]"#,
+                "[0].sets[1]: expected a number of `red` cubes",
+            ),
+            (
+                r#"[{"id": 1, "sets": [{"pink": 1}]}]"#,
+                "[0].sets[0]: expected `blue`, `green` or `red`",
+            ),
+        ] {
+            assert_eq!(Err(error.to_string()), from_json(json, &bag), "{json}");
+        }
+        assert!(from_json("[", &bag).is_err());
+    }
+}
@@ -0,0 +1,552 @@
+use super::{CubeSet, Day2, Game};
+use crate::{parse::Line, ParseError, Solution};
+
+/// A filter over games, like `any draw has red > 10 and blue < 3` or `minimal bag power > 500`.
+///
+/// ```text
+/// query      := ["games" "where"] condition
+/// condition  := and ("or" and)*
+/// and        := not ("and" not)*
+/// not        := "not" not | ("any" | "all") ("draw" | "draws") ["has" | "have"] condition
+///             | sum [("=" | "!=" | "<" | "<=" | ">" | ">=") sum]
+/// sum        := product (("+" | "-") product)*
+/// product    := atom (("*" | "/") atom)*
+/// atom       := number | "(" condition ")" | field
+/// field      := "id" | "sets" | "power" | "minimal" "bag" "power" | "minimal" colour
+///             | "cubes" | colour
+/// ```
+///
+/// `sets` is the number of sets of a game, `power` the power of its minimal bag over the colours
+/// of the bag, and `minimal red` the red cubes of its minimal bag. Inside `any draw` or `all
+/// draws`, whose condition reaches as far as possible, `red` is the number of red cubes of the
+/// draw and `cubes` its number of cubes. Numbers are integers, division rounds towards zero and
+/// comparisons with a division by zero are false.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    condition: Condition,
+    bag: CubeSet,
+}
+
+/// The games matching a query and aggregates over them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Matches {
+    pub ids: Vec<u32>,
+    pub id_sum: u64,
+    /// Sum of the powers of the minimal bags, over the colours of the bag.
+    pub power_sum: u64,
+}
+
+impl Query {
+    /// Parses and type-checks `text`, whose colours must be in `bag`. Errors read like
+    /// "column 5: expected a number".
+    pub fn parse(text: &str, bag: &CubeSet) -> Result<Query, String> {
+        let line = Line::new(Day2::DAY, 0, text);
+        Query::parse_line(&line, bag)
+            .map_err(|e| format!("column {}: expected {}", e.column, e.expected))
+    }
+
+    fn parse_line(line: &Line, bag: &CubeSet) -> Result<Query, ParseError> {
+        let mut parser = Parser {
+            line,
+            tokens: tokenize(line)?,
+            next: 0,
+        };
+        if parser.eat("games") {
+            parser.expect("where")?;
+        }
+        let expr = parser.condition()?;
+        if parser.peek().is_some() {
+            return Err(parser.error("`and`, `or`, an operator or the end of the query"));
+        }
+
+        let checker = Checker { line, bag };
+        Ok(Query {
+            condition: checker.condition(&expr, false)?,
+            bag: bag.clone(),
+        })
+    }
+
+    pub fn matches(&self, game: &Game) -> bool {
+        let minimal = game.minimal_bag();
+        let scope = Scope {
+            game,
+            power: minimal.power(self.bag.colours()),
+            minimal: &minimal,
+            draw: None,
+        };
+        self.condition.eval(&scope)
+    }
+
+    /// Evaluates the query on every game. Fails if the sum of the powers of the matching games
+    /// does not fit into a `u64`.
+    pub fn run(&self, games: &[Game]) -> Result<Matches, String> {
+        let mut matches = Matches::default();
+        for game in games.iter().filter(|game| self.matches(game)) {
+            matches.ids.push(game.id);
+            matches.id_sum += u64::from(game.id);
+            matches.power_sum = game
+                .minimal_bag()
+                .power(self.bag.colours())
+                .and_then(|power| matches.power_sum.checked_add(power))
+                .ok_or_else(|| {
+                    format!(
+                        "the sum of powers does not fit into 64 bits at game {}",
+                        game.id
+                    )
+                })?;
+        }
+        Ok(matches)
+    }
+}
+
+/// Splits a query into numbers, lowercase words and operators.
+fn tokenize<'a>(line: &Line<'a>) -> Result<Vec<&'a str>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut rest = line.text().trim_start();
+    while let Some(c) = rest.chars().next() {
+        let run = |f: fn(char) -> bool| rest.find(|c| !f(c)).unwrap_or(rest.len());
+        let len = if c.is_ascii_digit() {
+            run(|c| c.is_ascii_digit())
+        } else if c.is_ascii_lowercase() {
+            run(|c| c.is_ascii_lowercase())
+        } else if ["!=", "<=", ">="].iter().any(|op| rest.starts_with(op)) {
+            2
+        } else if "()+-*/=<>".contains(c) {
+            1
+        } else {
+            return Err(line.error(rest, "a number, a word, a parenthesis or an operator"));
+        };
+        tokens.push(&rest[..len]);
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+/// A parsed but not yet type-checked expression, starting at `at`.
+#[derive(Debug)]
+struct Expr<'a> {
+    at: &'a str,
+    kind: ExprKind<'a>,
+}
+
+#[derive(Debug)]
+enum ExprKind<'a> {
+    Number(i64),
+    Name(&'a str),
+    Minimal(&'a str),
+    Power,
+    Binary(Box<Expr<'a>>, &'a str, Box<Expr<'a>>),
+    Not(Box<Expr<'a>>),
+    Any(Box<Expr<'a>>),
+    All(Box<Expr<'a>>),
+}
+
+struct Parser<'a, 'l> {
+    line: &'l Line<'a>,
+    tokens: Vec<&'a str>,
+    next: usize,
+}
+
+impl<'a> Parser<'a, '_> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.next).copied()
+    }
+
+    /// The next token, or the empty end of the query.
+    fn at(&self) -> &'a str {
+        let text = self.line.text();
+        self.peek().unwrap_or(&text[text.len()..])
+    }
+
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        self.line.error(self.at(), expected)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let eaten = self.peek() == Some(token);
+        self.next += usize::from(eaten);
+        eaten
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), ParseError> {
+        match self.eat(token) {
+            true => Ok(()),
+            false => Err(self.error(format!("`{token}`"))),
+        }
+    }
+
+    /// Parses operands joined by any of `operators`, from left to right.
+    fn binary(
+        &mut self,
+        operators: &[&str],
+        operand: fn(&mut Self) -> Result<Expr<'a>, ParseError>,
+    ) -> Result<Expr<'a>, ParseError> {
+        let mut lhs = operand(self)?;
+        while let Some(op) = self.peek().filter(|token| operators.contains(token)) {
+            self.next += 1;
+            let rhs = operand(self)?;
+            lhs = Expr {
+                at: lhs.at,
+                kind: ExprKind::Binary(Box::new(lhs), op, Box::new(rhs)),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn condition(&mut self) -> Result<Expr<'a>, ParseError> {
+        self.binary(&["or"], |p| p.binary(&["and"], Self::not))
+    }
+
+    fn not(&mut self) -> Result<Expr<'a>, ParseError> {
+        let at = self.at();
+        let quantifier = self.peek();
+        let kind = if self.eat("not") {
+            ExprKind::Not(Box::new(self.not()?))
+        } else if self.eat("any") || self.eat("all") {
+            if !(self.eat("draw") || self.eat("draws")) {
+                return Err(self.error("`draw` or `draws`"));
+            }
+            let _ = self.eat("has") || self.eat("have");
+            let body = Box::new(self.condition()?);
+            match quantifier {
+                Some("any") => ExprKind::Any(body),
+                _ => ExprKind::All(body),
+            }
+        } else {
+            let lhs = self.sum()?;
+            match self.peek() {
+                Some(op @ ("=" | "!=" | "<" | "<=" | ">" | ">=")) => {
+                    self.next += 1;
+                    ExprKind::Binary(Box::new(lhs), op, Box::new(self.sum()?))
+                }
+                _ => return Ok(lhs),
+            }
+        };
+        Ok(Expr { at, kind })
+    }
+
+    fn sum(&mut self) -> Result<Expr<'a>, ParseError> {
+        self.binary(&["+", "-"], |p| p.binary(&["*", "/"], Self::atom))
+    }
+
+    fn atom(&mut self) -> Result<Expr<'a>, ParseError> {
+        let at = self.at();
+        let kind = match self.peek() {
+            Some("(") => {
+                self.next += 1;
+                let expr = self.condition()?;
+                self.expect(")")?;
+                return Ok(Expr { at, ..expr });
+            }
+            Some(token) if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                let n = self.line.parse(token, "a number that fits into 64 bits")?;
+                self.next += 1;
+                ExprKind::Number(n)
+            }
+            Some("minimal") => {
+                self.next += 1;
+                if self.eat("bag") {
+                    self.expect("power")?;
+                    ExprKind::Power
+                } else {
+                    match self.peek() {
+                        Some(colour) if colour.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                            self.next += 1;
+                            ExprKind::Minimal(colour)
+                        }
+                        _ => return Err(self.error("`bag power` or a colour")),
+                    }
+                }
+            }
+            Some("power") => {
+                self.next += 1;
+                ExprKind::Power
+            }
+            Some(word) if word.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                self.next += 1;
+                ExprKind::Name(word)
+            }
+            _ => return Err(self.error("a number, a field, `not`, `any`, `all` or `(`")),
+        };
+        Ok(Expr { at, kind })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Compare(Number, Comparison, Number),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+    Not(Box<Condition>),
+    Any(Box<Condition>),
+    All(Box<Condition>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Number {
+    Literal(i64),
+    Id,
+    Sets,
+    Power,
+    Minimal(String),
+    Drawn(String),
+    Cubes,
+    Arithmetic(Box<Number>, Operator, Box<Number>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// Turns expressions into conditions and numbers, rejecting numbers where conditions are
+/// expected and the other way round, unknown colours and draw fields outside of draws.
+struct Checker<'a, 'l> {
+    line: &'l Line<'a>,
+    bag: &'l CubeSet,
+}
+
+impl<'a> Checker<'a, '_> {
+    fn condition(&self, expr: &Expr<'a>, in_draw: bool) -> Result<Condition, ParseError> {
+        let condition = |e| self.condition(e, in_draw).map(Box::new);
+        Ok(match &expr.kind {
+            ExprKind::Binary(lhs, "and", rhs) => Condition::And(condition(lhs)?, condition(rhs)?),
+            ExprKind::Binary(lhs, "or", rhs) => Condition::Or(condition(lhs)?, condition(rhs)?),
+            ExprKind::Binary(lhs, op @ ("=" | "!=" | "<" | "<=" | ">" | ">="), rhs) => {
+                let comparison = match *op {
+                    "=" => Comparison::Eq,
+                    "!=" => Comparison::Ne,
+                    "<" => Comparison::Lt,
+                    "<=" => Comparison::Le,
+                    ">" => Comparison::Gt,
+                    _ => Comparison::Ge,
+                };
+                Condition::Compare(
+                    self.number(lhs, in_draw)?,
+                    comparison,
+                    self.number(rhs, in_draw)?,
+                )
+            }
+            ExprKind::Not(inner) => Condition::Not(condition(inner)?),
+            ExprKind::Any(body) => Condition::Any(Box::new(self.condition(body, true)?)),
+            ExprKind::All(body) => Condition::All(Box::new(self.condition(body, true)?)),
+            _ => {
+                return Err(self
+                    .line
+                    .error(expr.at, "a condition, like `id > 3`, not a number"))
+            }
+        })
+    }
+
+    fn number(&self, expr: &Expr<'a>, in_draw: bool) -> Result<Number, ParseError> {
+        Ok(match &expr.kind {
+            ExprKind::Number(n) => Number::Literal(*n),
+            ExprKind::Power => Number::Power,
+            ExprKind::Minimal(colour) => Number::Minimal(self.colour(expr.at, colour)?),
+            ExprKind::Name("id") => Number::Id,
+            ExprKind::Name("sets") => Number::Sets,
+            ExprKind::Name(name) if !in_draw && (*name == "cubes" || self.is_colour(name)) => {
+                return Err(self.line.error(
+                    expr.at,
+                    format!("a game field, `{name}` is a field of `any draw` or `all draws`"),
+                ))
+            }
+            ExprKind::Name("cubes") => Number::Cubes,
+            ExprKind::Name(name) => Number::Drawn(self.colour(expr.at, name)?),
+            ExprKind::Binary(lhs, op @ ("+" | "-" | "*" | "/"), rhs) => {
+                let operator = match *op {
+                    "+" => Operator::Add,
+                    "-" => Operator::Sub,
+                    "*" => Operator::Mul,
+                    _ => Operator::Div,
+                };
+                Number::Arithmetic(
+                    Box::new(self.number(lhs, in_draw)?),
+                    operator,
+                    Box::new(self.number(rhs, in_draw)?),
+                )
+            }
+            _ => return Err(self.line.error(expr.at, "a number, not a condition")),
+        })
+    }
+
+    fn is_colour(&self, name: &str) -> bool {
+        self.bag.colours().any(|colour| colour == name)
+    }
+
+    fn colour(&self, at: &'a str, name: &str) -> Result<String, ParseError> {
+        match self.is_colour(name) {
+            true => Ok(name.to_string()),
+            false => Err(self.line.error(
+                at,
+                format!(
+                    "`id`, `sets`, `power`, `minimal`, `cubes` or a colour: {}",
+                    self.bag.expected_colours()
+                ),
+            )),
+        }
+    }
+}
+
+/// What the fields of a query refer to while evaluating it.
+struct Scope<'g> {
+    game: &'g Game,
+    minimal: &'g CubeSet,
+    /// `None` if it does not fit into a `u64`.
+    power: Option<u64>,
+    draw: Option<&'g CubeSet>,
+}
+
+impl Condition {
+    fn eval(&self, scope: &Scope) -> bool {
+        match self {
+            Condition::Compare(lhs, comparison, rhs) => match (lhs.eval(scope), rhs.eval(scope)) {
+                (Some(a), Some(b)) => match comparison {
+                    Comparison::Eq => a == b,
+                    Comparison::Ne => a != b,
+                    Comparison::Lt => a < b,
+                    Comparison::Le => a <= b,
+                    Comparison::Gt => a > b,
+                    Comparison::Ge => a >= b,
+                },
+                _ => false,
+            },
+            Condition::And(lhs, rhs) => lhs.eval(scope) && rhs.eval(scope),
+            Condition::Or(lhs, rhs) => lhs.eval(scope) || rhs.eval(scope),
+            Condition::Not(inner) => !inner.eval(scope),
+            Condition::Any(body) => scope.draws().any(|scope| body.eval(&scope)),
+            Condition::All(body) => scope.draws().all(|scope| body.eval(&scope)),
+        }
+    }
+}
+
+impl<'g> Scope<'g> {
+    fn draws(&self) -> impl Iterator<Item = Scope<'g>> + '_ {
+        self.game.sets.iter().map(|set| Scope {
+            draw: Some(set),
+            ..*self
+        })
+    }
+}
+
+impl Number {
+    /// The value, or `None` after a division by zero or an overflow.
+    fn eval(&self, scope: &Scope) -> Option<i64> {
+        let draw = || {
+            scope
+                .draw
+                .expect("draw fields are checked to be inside draws")
+        };
+        match self {
+            Number::Literal(n) => Some(*n),
+            Number::Id => Some(scope.game.id.into()),
+            Number::Sets => i64::try_from(scope.game.sets.len()).ok(),
+            Number::Power => scope.power.and_then(|power| i64::try_from(power).ok()),
+            Number::Minimal(colour) => Some(scope.minimal.get(colour).into()),
+            Number::Drawn(colour) => Some(draw().get(colour).into()),
+            Number::Cubes => Some(draw().iter().map(|(_, n)| i64::from(n)).sum()),
+            Number::Arithmetic(lhs, operator, rhs) => {
+                let (a, b) = (lhs.eval(scope)?, rhs.eval(scope)?);
+                match operator {
+                    Operator::Add => a.checked_add(b),
+                    Operator::Sub => a.checked_sub(b),
+                    Operator::Mul => a.checked_mul(b),
+                    Operator::Div => a.checked_div(b),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use super::*;
+    use crate::days::day2::read_input;
+
+    fn run(query: &str) -> Matches {
+        let input = BufReader::new(File::open("inputs/day2/example1.txt").unwrap());
+        let games = read_input(input).unwrap();
+        Query::parse(query, &CubeSet::puzzle_bag())
+            .unwrap()
+            .run(&games)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_puzzle_parts() {
+        let possible = run("all draws have red <= 12 and green <= 13 and blue <= 14");
+        assert_eq!((vec![1, 2, 5], 8), (possible.ids, possible.id_sum));
+        assert_eq!(2286, run("games where id > 0").power_sum);
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let game = "2147483648 red, 2147483648 green, 2 blue";
+        let games =
+            read_input(format!("Game 1: {game}\nGame 2: {game}; 2 blue").as_bytes()).unwrap();
+        let query = |text| Query::parse(text, &CubeSet::puzzle_bag()).unwrap();
+        assert_eq!(
+            Err("the sum of powers does not fit into 64 bits at game 2".to_string()),
+            query("id > 0").run(&games)
+        );
+        assert_eq!(vec![1], query("id = 1").run(&games).unwrap().ids);
+
+        // a power beyond the range of a query's numbers compares as false either way
+        let games = read_input(&b"Game 1: 5000000 red, 5000000 green, 5000000 blue"[..]).unwrap();
+        assert!(!query("power > 0").matches(&games[0]));
+        assert!(!query("power <= 0").matches(&games[0]));
+    }
+
+    #[test]
+    fn test_queries() {
+        for (query, ids) in [
+            ("any draw has red > 10 and blue < 20", vec![3, 4]),
+            ("any draw has red > 10 and blue < 10", vec![3]),
+            ("(any draw has red > 10) and id > 3", vec![4]),
+            ("minimal bag power > 500", vec![3, 4]),
+            ("power >= 48 and not minimal red > 4", vec![1]),
+            ("sets = 2 or id * 2 = 6", vec![3, 5]),
+            ("all draws have cubes - blue < 5", vec![1, 2]),
+            ("minimal green / (minimal red - 4) = 0", vec![3, 4]),
+            ("any draw has not (red + green + blue = cubes)", vec![]),
+            ("any draw has (all draws have red > 0)", vec![3, 4, 5]),
+        ] {
+            assert_eq!(ids, run(query).ids, "{query}");
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let bag = CubeSet::puzzle_bag();
+        for (query, error) in [
+            ("red > 10", "column 1: expected a game field, `red` is a field of `any draw` or `all draws`"),
+            ("any draw has pink > 1", "column 14: expected `id`, `sets`, `power`, `minimal`, `cubes` or a colour: `blue`, `green` or `red`"),
+            ("id + 1", "column 1: expected a condition, like `id > 3`, not a number"),
+            ("id > 1 + (sets = 2)", "column 10: expected a number, not a condition"),
+            ("id > 1 sets", "column 8: expected `and`, `or`, an operator or the end of the query"),
+            ("(id > 1", "column 8: expected `)`"),
+            ("any set has red > 1", "column 5: expected `draw` or `draws`"),
+            ("minimal bag red", "column 13: expected `power`"),
+            ("id > #", "column 6: expected a number, a word, a parenthesis or an operator"),
+            ("games id > 1", "column 7: expected `where`"),
+            ("id >", "column 5: expected a number, a field, `not`, `any`, `all` or `(`"),
+        ] {
+            assert_eq!(Err(error.to_string()), Query::parse(query, &bag), "{query}");
+        }
+    }
+}
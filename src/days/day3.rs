@@ -0,0 +1,516 @@
+use std::{fmt::Write, io::BufRead};
+
+use crate::{
+    grid::{Grid, Point},
+    random::Rng,
+    ParseError, Solution, SolveError,
+};
+
+pub mod rules;
+
+use rules::GearRules;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = PartGraph;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+}
+
+fn part1(graph: &PartGraph) -> u64 {
+    graph
+        .edges()
+        .map(|(_, number)| u64::from(number.value))
+        .sum()
+}
+
+fn part2(graph: &PartGraph) -> Result<u64, SolveError> {
+    graph
+        .gear_ratio_sum(&GearRules::default())
+        .map_err(|gear| ratio_overflow(graph, gear))
+}
+
+/// The error of [`PartGraph::gear_ratio_sum`] failing at the symbol with index `gear`.
+pub fn ratio_overflow(graph: &PartGraph, gear: usize) -> SolveError {
+    let position = graph.symbols()[gear].position;
+    SolveError::new(
+        Day3::DAY,
+        format!(
+            "the sum of the gear ratios does not fit into 64 bits at line {}, column {}",
+            position.y + 1,
+            position.x + 1
+        ),
+    )
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<PartGraph, ParseError> {
+    read_schematic(reader).map(|schematic| schematic.graph(&GearRules::default()))
+}
+
+pub fn read_schematic<R: BufRead>(reader: R) -> Result<Schematic, ParseError> {
+    Schematic::new(Grid::parse(reader, Day3::DAY, "character", Some)?)
+}
+
+/// A number of the schematic on `row`, covering the columns `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+/// An engine schematic with the span of every number, indexed by the cells of its digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    grid: Grid<char>,
+    spans: Vec<Span>,
+    /// For every cell, the index of the span covering it, or `NO_SPAN`.
+    index: Grid<u32>,
+}
+
+const NO_SPAN: u32 = u32::MAX;
+
+impl Schematic {
+    /// Finds every number of `grid` in a single pass over its rows.
+    pub fn new(grid: Grid<char>) -> Result<Self, ParseError> {
+        let mut spans = Vec::new();
+        let mut index = Vec::with_capacity(grid.width() * grid.height());
+        for (y, row) in grid.rows().enumerate() {
+            let mut x = 0;
+            while let Some(offset) = row[x..].iter().position(char::is_ascii_digit) {
+                let start = x + offset;
+                let len = row[start..]
+                    .iter()
+                    .position(|c| !c.is_ascii_digit())
+                    .unwrap_or(row.len() - start);
+                x = start + len;
+                let value = row[start..x]
+                    .iter()
+                    .try_fold(0u32, |acc, c| {
+                        acc.checked_mul(10)?.checked_add(*c as u32 - '0' as u32)
+                    })
+                    .ok_or_else(|| ParseError {
+                        day: Day3::DAY,
+                        line: y + 1,
+                        column: start + 1,
+                        expected: "a number that fits into 32 bits".to_string(),
+                    })?;
+
+                let id = u32::try_from(spans.len()).expect("fewer numbers than cells");
+                index.resize(index.len() + offset, NO_SPAN);
+                index.resize(index.len() + len, id);
+                spans.push(Span {
+                    row: y,
+                    start,
+                    end: x,
+                    value,
+                });
+            }
+            index.resize(index.len() + row.len() - x, NO_SPAN);
+        }
+
+        let index = Grid::new(grid.width(), grid.height(), index);
+        Ok(Schematic { grid, spans, index })
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    /// Every number, row by row and from left to right.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// The number covering `point`, if any.
+    pub fn span_at(&self, point: Point) -> Option<&Span> {
+        self.index
+            .get(point)
+            .filter(|&&id| id != NO_SPAN)
+            .map(|&id| &self.spans[id as usize])
+    }
+
+    /// Cells that `rules` count as symbols, with their character.
+    pub fn symbols<'a>(&'a self, rules: &'a GearRules) -> impl Iterator<Item = (Point, char)> + 'a {
+        self.grid
+            .iter()
+            .filter(|(_, &c)| rules.is_symbol(c))
+            .map(|(point, &c)| (point, c))
+    }
+
+    /// The distinct numbers touching `point`, including diagonally, in the order of
+    /// [`Schematic::spans`].
+    pub fn adjacent_spans(&self, point: Point) -> impl Iterator<Item = &Span> {
+        self.adjacent_ids(point).map(|id| &self.spans[id as usize])
+    }
+
+    fn adjacent_ids(&self, point: Point) -> impl Iterator<Item = u32> {
+        // at most two numbers above, two below and one on each side
+        let mut ids = [NO_SPAN; 6];
+        let mut len = 0;
+        let columns = point.x.saturating_sub(1)..(point.x + 2).min(self.grid.width());
+        for y in point.y.saturating_sub(1)..(point.y + 2).min(self.grid.height()) {
+            for &id in &self.index.row(y)[columns.clone()] {
+                if id != NO_SPAN && !ids[..len].contains(&id) {
+                    ids[len] = id;
+                    len += 1;
+                }
+            }
+        }
+        ids[..len].sort_unstable();
+        ids.into_iter().take(len)
+    }
+
+    /// Connects every symbol of `rules` with the numbers it touches.
+    pub fn graph(&self, rules: &GearRules) -> PartGraph {
+        let mut symbols = Vec::new();
+        let mut symbol_edges = vec![0];
+        let mut numbers_of = Vec::new();
+        for (position, character) in self.symbols(rules) {
+            symbols.push(Symbol {
+                position,
+                character,
+            });
+            numbers_of.extend(self.adjacent_ids(position).map(|id| id as usize));
+            symbol_edges.push(numbers_of.len());
+        }
+
+        // the same edges from the side of the numbers, counted first and then filled in
+        let mut number_edges = vec![0; self.spans.len() + 1];
+        for &number in &numbers_of {
+            number_edges[number + 1] += 1;
+        }
+        for i in 1..number_edges.len() {
+            number_edges[i] += number_edges[i - 1];
+        }
+        let mut symbols_of = vec![0; numbers_of.len()];
+        let mut next = number_edges.clone();
+        for (symbol, edges) in symbol_edges.windows(2).enumerate() {
+            for &number in &numbers_of[edges[0]..edges[1]] {
+                symbols_of[next[number]] = symbol;
+                next[number] += 1;
+            }
+        }
+
+        PartGraph {
+            symbols,
+            numbers: self.spans.clone(),
+            symbol_edges,
+            numbers_of,
+            number_edges,
+            symbols_of,
+        }
+    }
+}
+
+/// A cell of the schematic that the rules count as a symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub position: Point,
+    pub character: char,
+}
+
+/// The bipartite graph of symbols and numbers, with an edge wherever a symbol touches a number,
+/// including diagonally. Symbols and numbers are referred to by their index in
+/// [`PartGraph::symbols`] and [`PartGraph::numbers`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartGraph {
+    symbols: Vec<Symbol>,
+    numbers: Vec<Span>,
+    /// The numbers of symbol `i` are `numbers_of[symbol_edges[i]..symbol_edges[i + 1]]`.
+    symbol_edges: Vec<usize>,
+    numbers_of: Vec<usize>,
+    /// The symbols of number `i` are `symbols_of[number_edges[i]..number_edges[i + 1]]`.
+    number_edges: Vec<usize>,
+    symbols_of: Vec<usize>,
+}
+
+impl PartGraph {
+    /// Every symbol, row by row and from left to right.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// Every number, row by row and from left to right.
+    pub fn numbers(&self) -> &[Span] {
+        &self.numbers
+    }
+
+    /// The numbers touching `symbol`, in the order of [`PartGraph::numbers`].
+    pub fn numbers_of(&self, symbol: usize) -> impl Iterator<Item = &Span> {
+        self.number_ids_of(symbol)
+            .iter()
+            .map(|&number| &self.numbers[number])
+    }
+
+    /// The symbols touching `number`, in the order of [`PartGraph::symbols`].
+    pub fn symbols_of(&self, number: usize) -> impl Iterator<Item = &Symbol> {
+        self.symbol_ids_of(number)
+            .iter()
+            .map(|&symbol| &self.symbols[symbol])
+    }
+
+    fn number_ids_of(&self, symbol: usize) -> &[usize] {
+        &self.numbers_of[self.symbol_edges[symbol]..self.symbol_edges[symbol + 1]]
+    }
+
+    fn symbol_ids_of(&self, number: usize) -> &[usize] {
+        &self.symbols_of[self.number_edges[number]..self.number_edges[number + 1]]
+    }
+
+    /// Every pair of a symbol and a number touching each other, by symbol.
+    pub fn edges(&self) -> impl Iterator<Item = (&Symbol, &Span)> {
+        (0..self.symbols.len()).flat_map(move |symbol| {
+            self.numbers_of(symbol)
+                .map(move |n| (&self.symbols[symbol], n))
+        })
+    }
+
+    /// The symbols touching any number with `value`.
+    pub fn symbols_touching(&self, value: u32) -> Vec<&Symbol> {
+        let mut ids = (0..self.numbers.len())
+            .filter(|&number| self.numbers[number].value == value)
+            .flat_map(|number| self.symbol_ids_of(number).iter().copied())
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.into_iter()
+            .map(|symbol| &self.symbols[symbol])
+            .collect()
+    }
+
+    /// The numbers touching no symbol, which are no part numbers.
+    pub fn orphans(&self) -> impl Iterator<Item = &Span> {
+        (0..self.numbers.len())
+            .filter(|&number| self.symbol_ids_of(number).is_empty())
+            .map(|number| &self.numbers[number])
+    }
+
+    /// The symbols that are gears under `rules`, by index.
+    pub fn gears<'a>(&'a self, rules: &'a GearRules) -> impl Iterator<Item = usize> + 'a {
+        (0..self.symbols.len())
+            .filter(|&symbol| rules.is_gear(self.symbols[symbol].character, self.degree(symbol)))
+    }
+
+    /// The would-be gears touching more numbers than `rules` allow, so that it is unclear which of
+    /// them the gear connects, by index.
+    pub fn ambiguous_gears<'a>(&'a self, rules: &'a GearRules) -> impl Iterator<Item = usize> + 'a {
+        (0..self.symbols.len()).filter(|&symbol| {
+            rules.is_ambiguous(self.symbols[symbol].character, self.degree(symbol))
+        })
+    }
+
+    /// The sum of the ratios of all gears under `rules`. Fails with the index of the first gear
+    /// whose ratio does not fit into a `u64`, alone or added to the ones before it.
+    pub fn gear_ratio_sum(&self, rules: &GearRules) -> Result<u64, usize> {
+        self.gears(rules).try_fold(0u64, |sum, gear| {
+            let ratio = rules
+                .combine
+                .apply(self.numbers_of(gear).map(|number| number.value));
+            ratio.and_then(|ratio| sum.checked_add(ratio)).ok_or(gear)
+        })
+    }
+
+    fn degree(&self, symbol: usize) -> usize {
+        self.number_ids_of(symbol).len()
+    }
+}
+
+/// Generates a `width` by `height` schematic with random symbols and numbers of up to `digits`
+/// digits, the puzzle's have 3.
+///
+/// # Panics
+///
+/// If `digits` is not in `1..=9`, so that every number fits into a `u32`.
+pub fn generate(width: usize, height: usize, digits: usize, seed: u64) -> String {
+    assert!((1..=9).contains(&digits), "{digits} digits per number");
+    const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+    let mut rng = Rng::new(seed);
+    let mut schematic = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        let mut x = 0;
+        while x < width {
+            match rng.below(10) {
+                0 => {
+                    let symbol = SYMBOLS[rng.below(SYMBOLS.len() as u64) as usize];
+                    schematic.push(symbol as char);
+                    x += 1;
+                }
+                1 | 2 => {
+                    let digits = (1 + rng.below(digits as u64) as usize).min(width - x);
+                    let value = rng.below(10u64.pow(digits as u32));
+                    write!(schematic, "{value:0digits$}").unwrap();
+                    x += digits;
+                    // keep the next number apart
+                    if x < width {
+                        schematic.push('.');
+                        x += 1;
+                    }
+                }
+                _ => {
+                    schematic.push('.');
+                    x += 1;
+                }
+            }
+        }
+        schematic.push('\n');
+    }
+    schematic
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader};
+
+    use super::*;
+
+    #[test]
+    fn test_spans() {
+        let schematic = read_schematic(&b"467..114..\n...*......\n..35..633."[..]).unwrap();
+        assert_eq!(
+            vec![
+                (0, 0, 3, 467),
+                (0, 5, 8, 114),
+                (2, 2, 4, 35),
+                (2, 6, 9, 633)
+            ],
+            schematic
+                .spans()
+                .iter()
+                .map(|s| (s.row, s.start, s.end, s.value))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(114),
+            schematic.span_at(Point::new(7, 0)).map(|s| s.value)
+        );
+        assert_eq!(None, schematic.span_at(Point::new(3, 0)));
+        let values = |point| {
+            schematic
+                .adjacent_spans(point)
+                .map(|s| s.value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![467, 35], values(Point::new(3, 1)));
+        assert_eq!(vec![114, 633], values(Point::new(8, 1)));
+    }
+
+    #[test]
+    fn test_graph() {
+        let input = BufReader::new(File::open("inputs/day3/example1.txt").unwrap());
+        let graph = read_input(input).unwrap();
+        assert_eq!(6, graph.symbols().len());
+        assert_eq!(10, graph.numbers().len());
+
+        let touching = graph.symbols_touching(467);
+        assert_eq!(
+            vec![(Point::new(3, 1), '*')],
+            touching
+                .iter()
+                .map(|s| (s.position, s.character))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![114, 58],
+            graph.orphans().map(|n| n.value).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![467, 35],
+            graph.numbers_of(0).map(|n| n.value).collect::<Vec<_>>()
+        );
+        let rules = GearRules::default();
+        assert_eq!(2, graph.gears(&rules).count());
+        assert_eq!(0, graph.ambiguous_gears(&rules).count());
+        for (number, span) in graph.numbers().iter().enumerate() {
+            for symbol in graph.symbols_of(number) {
+                let index = graph.symbols().iter().position(|s| s == symbol).unwrap();
+                assert!(graph.numbers_of(index).any(|n| n == span));
+            }
+        }
+        assert_eq!(
+            graph.edges().count(),
+            (0..graph.numbers().len())
+                .map(|n| graph.symbols_of(n).count())
+                .sum::<usize>()
+        );
+
+        let graph = read_input(&b"1.2\n.*.\n3.4"[..]).unwrap();
+        assert_eq!(vec![0], graph.ambiguous_gears(&rules).collect::<Vec<_>>());
+        assert_eq!(0, graph.gears(&rules).count());
+    }
+
+    #[test]
+    fn test_rules() {
+        let input = BufReader::new(File::open("inputs/day3/example1.txt").unwrap());
+        let schematic = read_schematic(input).unwrap();
+        let graph = |rules: &str| {
+            let rules = rules.parse::<GearRules>().unwrap();
+            (schematic.graph(&rules), rules)
+        };
+
+        let (stars, rules) = graph("symbols=*");
+        assert_eq!(3, stars.symbols().len());
+        assert_eq!(467 + 35 + 617 + 755 + 598, part1(&stars));
+        assert_eq!(Ok(467835), stars.gear_ratio_sum(&rules));
+
+        let (all, rules) = graph("arity=1+ combine=sum");
+        assert_eq!(Ok(502 + 617 + 1353), all.gear_ratio_sum(&rules));
+        let rules = "gears=any arity=1 combine=max".parse().unwrap();
+        assert_eq!(Ok(633 + 617 + 592 + 664), all.gear_ratio_sum(&rules));
+        let rules = "gears=any arity=1".parse().unwrap();
+        assert_eq!(vec![0, 5], all.ambiguous_gears(&rules).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_ratio_overflow() {
+        // 19 gears of two 9-digit numbers each, on every other row
+        let gear = "999999999*999999999\n...................\n";
+        let graph = read_input(gear.repeat(19).as_bytes()).unwrap();
+        assert_eq!(19, graph.gears(&GearRules::default()).count());
+        assert_eq!(
+            "day 3: the sum of the gear ratios does not fit into 64 bits at line 37, column 10",
+            part2(&graph).unwrap_err().to_string()
+        );
+
+        let graph = read_input(gear.repeat(18).as_bytes()).unwrap();
+        assert_eq!(Ok(18 * 999999999 * 999999999), part2(&graph));
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let error = read_schematic(&b"............\n.99999999999"[..]).unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_generate() {
+        let text = generate(50, 20, 9, 1);
+        let schematic = read_schematic(text.as_bytes()).unwrap();
+        assert_eq!(
+            (50, 20),
+            (schematic.grid().width(), schematic.grid().height())
+        );
+        assert!(!schematic.spans().is_empty());
+        for span in schematic.spans() {
+            let digits = &schematic.grid().row(span.row)[span.start..span.end];
+            let text = digits.iter().collect::<String>();
+            assert_eq!(span.value, text.parse::<u32>().unwrap());
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::str::FromStr;
+
+use super::Day3;
+use crate::{parse::Line, ParseError, Solution};
+
+/// Which cells are symbols, which symbols are gears and how the numbers of a gear combine. The
+/// default is the puzzle's: every character but `.` is a symbol, and a `*` touching exactly two
+/// numbers is a gear whose ratio is their product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GearRules {
+    pub symbols: Chars,
+    /// Only symbols can be gears, [`Chars::Any`] makes every symbol one.
+    pub gears: Chars,
+    pub arity: Arity,
+    pub combine: Combine,
+}
+
+/// A set of characters. Digits always belong to numbers and are never in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Chars {
+    /// Every character but `.`.
+    Any,
+    Only(Vec<char>),
+}
+
+/// How many numbers a gear touches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+/// How the numbers touching a gear combine into its ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+    /// The largest number, 0 for none.
+    Max,
+}
+
+impl Default for GearRules {
+    fn default() -> Self {
+        GearRules {
+            symbols: Chars::Any,
+            gears: Chars::Only(vec!['*']),
+            arity: Arity::Exactly(2),
+            combine: Combine::Product,
+        }
+    }
+}
+
+impl GearRules {
+    pub fn is_symbol(&self, c: char) -> bool {
+        !c.is_ascii_digit() && self.symbols.contains(c)
+    }
+
+    pub fn is_gear(&self, c: char, numbers: usize) -> bool {
+        self.is_symbol(c) && self.gears.contains(c) && self.arity.allows(numbers)
+    }
+
+    /// Whether a gear touching this many numbers has too many of them to tell which belong to it,
+    /// always false for [`Arity::AtLeast`].
+    pub fn is_ambiguous(&self, c: char, numbers: usize) -> bool {
+        let too_many = matches!(self.arity, Arity::Exactly(n) if numbers > n);
+        too_many && self.is_symbol(c) && self.gears.contains(c)
+    }
+}
+
+impl Chars {
+    pub fn contains(&self, c: char) -> bool {
+        match self {
+            Chars::Any => c != '.',
+            Chars::Only(chars) => chars.contains(&c),
+        }
+    }
+}
+
+impl Arity {
+    pub fn allows(self, numbers: usize) -> bool {
+        match self {
+            Arity::Exactly(n) => numbers == n,
+            Arity::AtLeast(n) => numbers >= n,
+        }
+    }
+}
+
+impl Combine {
+    /// The ratio of a gear touching `numbers`, or `None` if it does not fit into a `u64`.
+    pub fn apply(self, numbers: impl IntoIterator<Item = u32>) -> Option<u64> {
+        let mut numbers = numbers.into_iter().map(u64::from);
+        match self {
+            Combine::Product => numbers.try_fold(1, u64::checked_mul),
+            Combine::Sum => numbers.try_fold(0, u64::checked_add),
+            Combine::Max => Some(numbers.max().unwrap_or(0)),
+        }
+    }
+}
+
+impl FromStr for GearRules {
+    type Err = String;
+
+    /// Parses settings like `gears=*# arity=2+ combine=sum`, separated by whitespace. `symbols`
+    /// and `gears` take `any` or the characters themselves, `arity` a number of numbers with a `+`
+    /// for at least that many, and `combine` one of `product`, `sum` and `max`. Settings not
+    /// given keep their default. Errors read like "column 7: expected a number".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(Day3::DAY, 0, s);
+        parse_rules(&line).map_err(|e| format!("column {}: expected {}", e.column, e.expected))
+    }
+}
+
+fn parse_rules(line: &Line) -> Result<GearRules, ParseError> {
+    let mut rules = GearRules::default();
+    let mut given = Vec::new();
+    for setting in line.text().split_whitespace() {
+        let Some((key, value)) = setting.split_once('=') else {
+            return Err(line.error(&setting[setting.len()..], "`=`"));
+        };
+        if given.contains(&key) {
+            return Err(line.error(key, "a setting not given before"));
+        }
+        match key {
+            "symbols" => rules.symbols = parse_chars(line, value)?,
+            "gears" => rules.gears = parse_chars(line, value)?,
+            "arity" => {
+                rules.arity = match value.strip_suffix('+') {
+                    Some(n) => Arity::AtLeast(line.parse(n, "a number of numbers")?),
+                    None => Arity::Exactly(line.parse(value, "a number of numbers")?),
+                }
+            }
+            "combine" => {
+                rules.combine = match value {
+                    "product" => Combine::Product,
+                    "sum" => Combine::Sum,
+                    "max" => Combine::Max,
+                    _ => return Err(line.error(value, "`product`, `sum` or `max`")),
+                }
+            }
+            _ => return Err(line.error(key, "`symbols`, `gears`, `arity` or `combine`")),
+        }
+        given.push(key);
+    }
+    Ok(rules)
+}
+
+fn parse_chars(line: &Line, value: &str) -> Result<Chars, ParseError> {
+    if value == "any" {
+        return Ok(Chars::Any);
+    }
+    match value.find(|c: char| c.is_ascii_digit()) {
+        Some(digit) => Err(line.error(&value[digit..], "a character other than a digit")),
+        None if value.is_empty() => Err(line.error(value, "`any` or some characters")),
+        None => Ok(Chars::Only(value.chars().collect())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(GearRules::default()), "".parse());
+        assert_eq!(
+            Ok(GearRules {
+                symbols: Chars::Only(vec!['*', '#']),
+                gears: Chars::Any,
+                arity: Arity::AtLeast(1),
+                combine: Combine::Max,
+            }),
+            " symbols=*#  gears=any arity=1+ combine=max".parse()
+        );
+        for (rules, error) in [
+            ("gears", "column 6: expected `=`"),
+            (
+                "colour=red",
+                "column 1: expected `symbols`, `gears`, `arity` or `combine`",
+            ),
+            (
+                "arity=2 arity=3",
+                "column 9: expected a setting not given before",
+            ),
+            ("arity=two", "column 7: expected a number of numbers"),
+            (
+                "combine=min",
+                "column 9: expected `product`, `sum` or `max`",
+            ),
+            (
+                "symbols=*1",
+                "column 10: expected a character other than a digit",
+            ),
+            ("gears=", "column 7: expected `any` or some characters"),
+        ] {
+            assert_eq!(
+                Err(error.to_string()),
+                rules.parse::<GearRules>(),
+                "{rules}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules = GearRules::default();
+        assert!(rules.is_symbol('#') && !rules.is_symbol('.') && !rules.is_symbol('7'));
+        assert!(rules.is_gear('*', 2) && !rules.is_gear('*', 3) && !rules.is_gear('#', 2));
+        assert!(rules.is_ambiguous('*', 3) && !rules.is_ambiguous('*', 2));
+
+        // gears must be symbols
+        let rules = "symbols=# gears=*# arity=1+".parse::<GearRules>().unwrap();
+        assert!(rules.is_gear('#', 5) && !rules.is_gear('*', 5) && !rules.is_gear('#', 0));
+        assert!(!rules.is_ambiguous('#', 5));
+
+        assert_eq!(Some(24), Combine::Product.apply([2, 3, 4]));
+        assert_eq!(Some(9), Combine::Sum.apply([2, 3, 4]));
+        assert_eq!(Some(4), Combine::Max.apply([2, 4, 3]));
+        assert_eq!(
+            (Some(1), Some(0)),
+            (Combine::Product.apply([]), Combine::Max.apply([]))
+        );
+        assert_eq!(None, Combine::Product.apply([u32::MAX; 3]));
+    }
+}
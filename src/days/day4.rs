@@ -0,0 +1,111 @@
+use std::{collections::HashMap, io::BufRead};
+
+use crate::{
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+fn part1(cards: &[Card]) -> i32 {
+    cards.iter().fold(0, |acc, card| {
+        let result = card.have.iter().fold(0, |acc, have| {
+            if card.winning.contains(have) {
+                acc + 1
+            } else {
+                acc
+            }
+        });
+
+        acc + if result > 0 { 1 << (result - 1) } else { 0 }
+    })
+}
+
+fn part2(cards: &[Card]) -> i32 {
+    let mut cards_count: HashMap<i32, i32> =
+        HashMap::from_iter(cards.iter().map(|card| (card.id, 1)));
+    cards.iter().for_each(|card| {
+        let result = card.have.iter().fold(0, |acc, have| {
+            if card.winning.contains(have) {
+                acc + 1
+            } else {
+                acc
+            }
+        });
+
+        if result > 0 {
+            for id in (card.id + 1)..=(card.id + result) {
+                *cards_count.entry(id).or_insert(0) += cards_count[&card.id];
+            }
+        }
+    });
+
+    cards_count.values().sum()
+}
+
+pub struct Card {
+    id: i32,
+    winning: Vec<i32>,
+    have: Vec<i32>,
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<Card>, ParseError> {
+    lines(reader, Day4::DAY)
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l?;
+            let line = Line::new(Day4::DAY, i, &l);
+            let (card_name, cards) = line.split_once(&l, ": ")?;
+            let card_id = line.split_once(card_name, " ")?.1.trim_start();
+            let card_id = line.parse::<i32>(card_id, "card id")?;
+
+            let cards = line.split_once(cards, " | ")?;
+
+            let winning = line.parse_all(cards.0, "winning number")?;
+            let have = line.parse_all(cards.1, "number")?;
+
+            Ok(Card {
+                id: card_id,
+                winning,
+                have,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day4>(&[
+            (
+                "Card 1: 41 48 | 83 x6",
+                "line 1, column 20: expected number",
+            ),
+            ("Card 1 41 | 83", "line 1, column 1: expected `: `"),
+        ]);
+    }
+}
@@ -0,0 +1,189 @@
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::{collections::HashMap, io::BufRead, ops::Range, sync::atomic::AtomicI64};
+
+use crate::{
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (Seeds, Maps);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(&input.0, &input.1))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        part2(&input.0, &input.1)
+    }
+}
+
+fn part1(seeds: &Seeds, maps: &Maps) -> i64 {
+    let mut locations = Vec::new();
+    for seed in seeds {
+        let mut location = *seed;
+        for map in maps {
+            for (src_range, dest_range) in map {
+                if src_range.contains(&location) {
+                    let diff = location - src_range.start;
+                    location = dest_range.start + diff;
+                    break;
+                }
+            }
+        }
+
+        locations.push(location);
+    }
+
+    *locations.iter().min().unwrap()
+}
+
+fn part2(seeds: &Seeds, maps: &Maps) -> Result<i64, SolveError> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(SolveError::new(
+            Day5::DAY,
+            format!("{} seed numbers do not pair up into ranges", seeds.len()),
+        ));
+    }
+    let seeds = seeds
+        .chunks(2)
+        .map(|chunk| chunk[0]..chunk[0] + chunk[1])
+        .collect_vec();
+
+    Ok(seeds
+        .par_iter()
+        .map(|seed_range| {
+            let min_location = AtomicI64::new(i64::MAX);
+            seed_range.clone().into_par_iter().for_each(|seed| {
+                let mut location = seed;
+                for map in maps {
+                    for (src_range, dest_range) in map {
+                        if src_range.contains(&location) {
+                            let diff = location - src_range.start;
+                            location = dest_range.start + diff;
+                            break;
+                        }
+                    }
+                }
+
+                min_location.fetch_min(location, std::sync::atomic::Ordering::Relaxed);
+            });
+            min_location.into_inner()
+        })
+        .min()
+        .unwrap())
+}
+
+type Seeds = Vec<i64>;
+type Maps = Vec<HashMap<Range<i64>, Range<i64>>>;
+
+fn read_input<R: BufRead>(reader: R) -> Result<(Seeds, Maps), ParseError> {
+    let mut seeds = None;
+    let mut maps = Vec::new();
+    let mut current_map = HashMap::new();
+    for (i, l) in lines(reader, Day5::DAY).enumerate() {
+        let l = l?;
+        let line = Line::new(Day5::DAY, i, &l);
+
+        if l.is_empty() {
+            continue;
+        }
+
+        if l.starts_with("seeds") {
+            let (_, numbers) = line.split_once(&l, ":")?;
+            let numbers = line.parse_all(numbers, "seed number")?;
+            if numbers.is_empty() {
+                return Err(line.error_at_end("seed number"));
+            }
+            seeds = Some(numbers);
+
+            continue;
+        }
+
+        if l.ends_with("map:") {
+            if !current_map.is_empty() {
+                maps.push(current_map);
+            }
+            current_map = HashMap::new();
+            continue;
+        }
+
+        if l.starts_with(|c: char| c.is_ascii_digit()) {
+            let (dest_start, src_start, len) = {
+                let mut parts = l.split_whitespace();
+                let mut next = |expected| {
+                    let part = parts.next().ok_or_else(|| line.error_at_end(expected))?;
+                    line.parse::<i64>(part, expected)
+                };
+                let dest_start = next("destination range start")?;
+                let src_start = next("source range start")?;
+                let len = next("range length")?;
+                (dest_start, src_start, len)
+            };
+
+            let dest_range = dest_start..dest_start + len;
+            let src_range = src_start..src_start + len;
+            current_map.insert(src_range, dest_range);
+        } else {
+            return Err(line.error(&l, "seeds, map header or range"));
+        }
+    }
+    maps.push(current_map);
+
+    let seeds = seeds.ok_or(ParseError {
+        day: Day5::DAY,
+        line: 1,
+        column: 1,
+        expected: "`seeds:` line".to_string(),
+    })?;
+    Ok((seeds, maps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day5>(&[
+            ("seeds: 79 x", "line 1, column 11: expected seed number"),
+            (
+                "seeds: 1\n\nsoil map:\n50 98",
+                "line 4, column 6: expected range length",
+            ),
+            (
+                "seeds: 1\nfoo",
+                "line 2, column 1: expected seeds, map header or range",
+            ),
+            ("seeds: ", "line 1, column 8: expected seed number"),
+        ]);
+    }
+
+    #[test]
+    fn test_unpaired_seeds() {
+        let input = Day5::parse(
+            &b"seeds: 79 14 55
+
+soil map:
+50 98 2"[..],
+        )
+        .unwrap();
+        assert_eq!(Ok(14), Day5::part1(&input));
+        assert_eq!(
+            "day 5: 3 seed numbers do not pair up into ranges",
+            Day5::part2(&input).unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::{
+    math,
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Race>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+}
+
+fn part1(races: &[Race]) -> u64 {
+    races.iter().map(ways_to_win).product()
+}
+
+fn part2(races: &[Race]) -> Result<u64, SolveError> {
+    let (distance, record) = races
+        .iter()
+        .fold((String::new(), String::new()), |acc, race| {
+            (
+                acc.0 + &race.distance.to_string(),
+                acc.1 + &race.record.to_string(),
+            )
+        });
+    let parse = |digits: String| {
+        digits
+            .parse()
+            .map_err(|_| SolveError::new(Day6::DAY, format!("{digits} does not fit into 64 bits")))
+    };
+    Ok(ways_to_win(&Race {
+        distance: parse(distance)?,
+        record: parse(record)?,
+    }))
+}
+
+/// Number of hold times `t` with `t * (distance - t) > record`, between the roots of the quadratic.
+fn ways_to_win(race: &Race) -> u64 {
+    // wide enough for the square of any distance, hold times never exceed it
+    let (distance, record) = (u128::from(race.distance), u128::from(race.record));
+    let beats = |t: u128| t * (distance - t) > record;
+    if !beats(distance / 2) {
+        return 0;
+    }
+
+    let discriminant = distance * distance - 4 * record;
+    // the integer root can be off by one from the real one, in either direction
+    let mut lowest = (distance - math::isqrt(discriminant)) / 2;
+    while !beats(lowest) {
+        lowest += 1;
+    }
+    while lowest > 0 && beats(lowest - 1) {
+        lowest -= 1;
+    }
+    (distance - 2 * lowest + 1) as u64
+}
+
+pub struct Race {
+    distance: u64,
+    record: u64,
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<Race>, ParseError> {
+    let lines = lines(reader, Day6::DAY).collect::<Result<Vec<_>, _>>()?;
+    let input = lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let line = Line::new(Day6::DAY, i, l);
+            let (_, values) = line.split_once(l, ":")?;
+            line.parse_all::<u64>(values, "number")
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match input.as_slice() {
+        [times, distances] if times.len() == distances.len() => Ok(times
+            .iter()
+            .interleave(distances.iter())
+            .tuples()
+            .map(|(&distance, &record)| Race { distance, record })
+            .collect_vec()),
+        [times, _] => Err(Line::new(Day6::DAY, 1, &lines[1])
+            .error_at_end(format!("exactly {} record distances", times.len()))),
+        _ => Err(ParseError {
+            day: Day6::DAY,
+            line: input.len().min(2) + 1,
+            column: 1,
+            expected: "exactly two lines: times and record distances".to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day6>(&[
+            (
+                "Time: 7 15\nDistance: 9",
+                "line 2, column 12: expected exactly 2 record distances",
+            ),
+            (
+                "Time: 7",
+                "line 2, column 1: expected exactly two lines: times and record distances",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        for distance in 0..60 {
+            for record in 0..1000 {
+                let race = Race { distance, record };
+                let expected = (0..=distance)
+                    .filter(|t| t * (distance - t) > record)
+                    .count();
+                assert_eq!(expected as u64, ways_to_win(&race), "{distance} {record}");
+            }
+        }
+        let race = Race {
+            distance: u64::MAX,
+            record: 0,
+        };
+        assert_eq!(u64::MAX - 1, ways_to_win(&race));
+    }
+
+    #[test]
+    fn test_long_race() {
+        let races = Day6::parse(&b"Time: 7 15\nDistance: 9 40"[..]).unwrap();
+        assert_eq!(Ok(4 * 8), Day6::part1(&races));
+        assert_eq!(Ok(712), Day6::part2(&races));
+
+        let races = Day6::parse(&b"Time: 9999999999 9999999999\nDistance: 1 1"[..]).unwrap();
+        assert_eq!(
+            "day 6: 99999999999999999999 does not fit into 64 bits",
+            Day6::part2(&races).unwrap_err().to_string()
+        );
+    }
+}
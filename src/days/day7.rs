@@ -0,0 +1,208 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = (Vec<Player>, Vec<Player>);
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        let lines = lines(reader, Self::DAY).collect::<Result<Vec<_>, _>>()?;
+        Ok((read_input(&lines, false)?, read_input(&lines, true)?))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(calculate_winnings(&input.0))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(calculate_winnings(&input.1))
+    }
+}
+
+fn calculate_winnings(players: &[Player]) -> i32 {
+    players
+        .iter()
+        .sorted()
+        .rev()
+        .enumerate()
+        .map(|(i, p)| p.bid * (i as i32 + 1))
+        .sum()
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Hash, Debug, Copy, Clone)]
+struct Card(i32);
+
+impl Card {
+    /// What a `J` becomes when jokers are wild, weaker than any other card.
+    const JOKER: Card = Card(1);
+
+    fn from_label(c: char) -> Option<Self> {
+        match c {
+            'A' => Some(Card(14)),
+            'K' => Some(Card(13)),
+            'Q' => Some(Card(12)),
+            'J' => Some(Card(11)),
+            'T' => Some(Card(10)),
+            '2'..='9' => Some(Card(c.to_digit(10).unwrap() as i32)),
+            _ => None,
+        }
+    }
+}
+
+type Cards = [Card; 5];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Player {
+    hand: HandResult,
+    cards: Cards,
+    bid: i32,
+}
+
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Copy, Clone)]
+enum HandResult {
+    Five,
+    Four,
+    FullHouse,
+    Three,
+    TwoPairs,
+    Pair,
+    HighCard,
+}
+
+impl Ord for Player {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let a = self.hand as i32;
+        let b = other.hand as i32;
+
+        // the first differing card decides between hands of the same type
+        a.cmp(&b).then_with(|| other.cards.cmp(&self.cards))
+    }
+}
+
+impl PartialOrd for Player {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Cards> for HandResult {
+    fn from(cards: Cards) -> Self {
+        let jokers = cards.iter().filter(|&c| *c == Card::JOKER).count();
+        let mut cards_grouped: HashMap<Card, usize> = cards
+            .iter()
+            .filter(|&c| c != &Card::JOKER)
+            .map(|card| (*card, cards.iter().filter(|c| *c == card).count()))
+            .collect();
+
+        if jokers == 5 {
+            return HandResult::Five;
+        } else if jokers > 0 {
+            let max = cards_grouped.values().max().unwrap();
+            let max_card = cards_grouped
+                .iter()
+                .find(|(_, &v)| v == *max)
+                .map(|(&k, _)| k)
+                .unwrap();
+
+            cards_grouped.insert(max_card, *max + jokers);
+        }
+
+        match cards_grouped.values().max().unwrap() {
+            5 => HandResult::Five,
+            4 => HandResult::Four,
+            3 => {
+                if cards_grouped.len() == 2 {
+                    HandResult::FullHouse
+                } else {
+                    HandResult::Three
+                }
+            }
+            2 => {
+                if cards_grouped.len() == 3 {
+                    HandResult::TwoPairs
+                } else {
+                    HandResult::Pair
+                }
+            }
+            _ => HandResult::HighCard,
+        }
+    }
+}
+
+fn read_input(input: &[String], jokers: bool) -> Result<Vec<Player>, ParseError> {
+    // players with the same hand would tie for a rank
+    let mut hands = HashSet::new();
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let line = Line::new(Day7::DAY, i, l);
+            let (hand, bid) = line.split_once(l, " ")?;
+            if !hands.insert(hand) {
+                return Err(line.error(hand, "a hand not dealt before"));
+            }
+
+            let cards = hand
+                .char_indices()
+                .map(|(i, c)| match c {
+                    'J' if jokers => Ok(Card::JOKER),
+                    _ => Card::from_label(c)
+                        .ok_or_else(|| line.error(&hand[i..], "one of the cards `AKQJT98765432`")),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let cards: Cards = cards
+                .try_into()
+                .map_err(|_| line.error(hand, "hand of exactly 5 cards"))?;
+
+            Ok(Player {
+                hand: cards.into(),
+                cards,
+                bid: line.parse(bid, "bid")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day7>(&[
+            (
+                "32T3K 765\nAB123 1",
+                "line 2, column 2: expected one of the cards `AKQJT98765432`",
+            ),
+            (
+                "32T3 765",
+                "line 1, column 1: expected hand of exactly 5 cards",
+            ),
+            ("32T3K x", "line 1, column 7: expected bid"),
+            (
+                "32T3K 765\nKK677 28\n32T3K 1",
+                "line 3, column 1: expected a hand not dealt before",
+            ),
+            // jokers are written as `J` like the jacks
+            (
+                "32T3j 765",
+                "line 1, column 5: expected one of the cards `AKQJT98765432`",
+            ),
+        ]);
+    }
+}
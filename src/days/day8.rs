@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::{
+    math,
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Game;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+}
+
+fn part1(game: &Game) -> Result<u64, SolveError> {
+    if !game.map.contains_key("AAA") {
+        return Err(SolveError::new(
+            Day8::DAY,
+            "there is no node AAA to start from",
+        ));
+    }
+    steps(game, "AAA", |pos| pos == "ZZZ")
+        .ok_or_else(|| SolveError::new(Day8::DAY, "ZZZ can not be reached from AAA"))
+}
+
+fn part2(game: &Game) -> Result<u64, SolveError> {
+    let starts = game
+        .map
+        .keys()
+        .filter(|k| k.contains('A'))
+        .sorted()
+        .collect_vec();
+    if starts.is_empty() {
+        return Err(SolveError::new(
+            Day8::DAY,
+            "there are no nodes with an A to start from",
+        ));
+    }
+    let positions = starts
+        .into_iter()
+        .map(|start| {
+            steps(game, start, |pos| pos.ends_with('Z')).ok_or_else(|| {
+                SolveError::new(
+                    Day8::DAY,
+                    format!("no node ending with Z can be reached from {start}"),
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    math::lcm_all(positions)
+        .ok_or_else(|| SolveError::new(Day8::DAY, "the number of steps does not fit into 64 bits"))
+}
+
+/// Steps from `start` to the first node `end` accepts, `None` if the walk never gets there.
+fn steps(game: &Game, start: &str, end: impl Fn(&str) -> bool) -> Option<u64> {
+    // after a step for every node and direction, the walk can only repeat itself
+    let limit = game.map.len() * game.directions.len();
+    let mut pos = start;
+    for (steps, c) in (1..=limit as u64).zip(game.directions.iter().cycle()) {
+        let (left, right) = &game.map[pos];
+        pos = if *c == 'L' { left } else { right };
+        if end(pos) {
+            return Some(steps);
+        }
+    }
+    None
+}
+
+type Map = HashMap<String, (String, String)>;
+pub struct Game {
+    map: Map,
+    directions: Vec<char>,
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Game, ParseError> {
+    let lines = lines(reader, Day8::DAY).collect::<Result<Vec<_>, _>>()?;
+    let mut map = Map::new();
+    let mut directions = Vec::new();
+    // where each node is referred to, checked once all nodes are known
+    let mut references = Vec::new();
+    for (i, l) in lines.iter().enumerate() {
+        let line = Line::new(Day8::DAY, i, l);
+        if let Some(parts) = l.split_once('=') {
+            let from = parts.0.trim();
+            let to = parts.1.trim();
+            let to = to
+                .strip_prefix('(')
+                .ok_or_else(|| line.error(to, "`(`"))?
+                .strip_suffix(')')
+                .ok_or_else(|| line.error_at_end("`)`"))?;
+
+            let to = line.split_once(to, ",")?;
+            let to = (to.0.trim(), to.1.trim());
+            references.extend([(line, to.0), (line, to.1)]);
+            map.insert(from.to_string(), (to.0.to_string(), to.1.to_string()));
+        } else if !l.is_empty() {
+            if let Some(i) = l.find(|c| c != 'L' && c != 'R') {
+                return Err(line.error(&l[i..], "`L` or `R`"));
+            }
+            directions = l.chars().collect();
+        }
+    }
+
+    if directions.is_empty() {
+        return Err(ParseError {
+            day: Day8::DAY,
+            line: 1,
+            column: 1,
+            expected: "a line of `L` and `R` directions".to_string(),
+        });
+    }
+    if let Some((line, node)) = references.iter().find(|(_, node)| !map.contains_key(*node)) {
+        return Err(line.error(node, "a node with a line of its own"));
+    }
+    Ok(Game { map, directions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day8>(&[
+            ("LRX", "line 1, column 3: expected `L` or `R`"),
+            ("AAA = BBB, CCC)", "line 1, column 7: expected `(`"),
+            ("AAA = (BBB CCC)", "line 1, column 8: expected `,`"),
+            (
+                "LR\n\nAAA = (AAA, ZZZ)",
+                "line 3, column 13: expected a node with a line of its own",
+            ),
+            (
+                "AAA = (AAA, AAA)",
+                "line 1, column 1: expected a line of `L` and `R` directions",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // always left, from AAA to BBB and then BBB forever
+        let input = "L\n\nAAA = (BBB, ZZZ)\nBBB = (BBB, AAA)\nZZZ = (ZZZ, ZZZ)";
+        let game = Day8::parse(input.as_bytes()).unwrap();
+        assert_eq!(
+            Err("day 8: ZZZ can not be reached from AAA".to_string()),
+            Day8::part1(&game).map_err(|e| e.to_string())
+        );
+        assert_eq!(
+            Err("day 8: no node ending with Z can be reached from AAA".to_string()),
+            Day8::part2(&game).map_err(|e| e.to_string())
+        );
+
+        let game = Day8::parse(&b"LR\n\nBBB = (BBB, BBB)"[..]).unwrap();
+        assert_eq!(
+            Err("day 8: there is no node AAA to start from".to_string()),
+            Day8::part1(&game).map_err(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn test_steps_overflow() {
+        // a chain of nodes from nA to nZ for each prime, all walked at once in their product
+        let mut input = "L\n\n".to_string();
+        for (n, prime) in [701, 709, 719, 727, 733, 739, 743].into_iter().enumerate() {
+            let nodes = (0..prime)
+                .map(|i| match i {
+                    0 => format!("{n}A"),
+                    _ => format!("{n}x{i}"),
+                })
+                .chain([format!("{n}Z")])
+                .collect_vec();
+            for (from, to) in nodes
+                .iter()
+                .zip(nodes.iter().skip(1).chain([&nodes[prime]]))
+            {
+                input += &format!("{from} = ({to}, {to})\n");
+            }
+        }
+        let game = Day8::parse(input.as_bytes()).unwrap();
+        assert_eq!(
+            Err("day 8: the number of steps does not fit into 64 bits".to_string()),
+            Day8::part2(&game).map_err(|e| e.to_string())
+        );
+    }
+}
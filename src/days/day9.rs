@@ -0,0 +1,90 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::{
+    parse::{lines, Line},
+    ParseError, Solution, SolveError,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {
+        read_input(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {
+        Ok(interpolate(input).1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {
+        Ok(interpolate(input).0)
+    }
+}
+
+fn interpolate(input: &[Vec<i32>]) -> (i32, i32) {
+    input.iter().fold((0, 0), |acc, v| {
+        let mut diffs = vec![v.clone()];
+        let mut curr_diff = v.clone();
+
+        // a single value has no differences, which count as zeros
+        while curr_diff.iter().any(|v| *v != 0) {
+            let new_diff = curr_diff
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| b - a)
+                .collect_vec();
+            diffs.push(new_diff.clone());
+            curr_diff = new_diff;
+        }
+
+        let mut new_first = 0;
+        diffs.iter().skip(1).rev().for_each(|v| {
+            new_first = v.first().unwrap_or(&0) - new_first;
+        });
+        let post = diffs.iter().filter_map(|v| v.last()).sum::<i32>();
+        (acc.0 + diffs[0][0] - new_first, acc.1 + post)
+    })
+}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<Vec<i32>>, ParseError> {
+    lines(reader, Day9::DAY)
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l?;
+            let line = Line::new(Day9::DAY, i, &l);
+            let history = line.parse_all(&l, "number")?;
+            if history.is_empty() {
+                return Err(line.error_at_end("number"));
+            }
+            Ok(history)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::assert_parse_errors;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_parse_errors::<Day9>(&[
+            ("0 3 6\n0 3 x", "line 2, column 5: expected number"),
+            ("0 3 6\n\n1 2", "line 2, column 1: expected number"),
+        ]);
+    }
+
+    #[test]
+    fn test_short_histories() {
+        let input = Day9::parse(&b"5\n1 3"[..]).unwrap();
+        assert_eq!((5 - 1, 5 + 5), interpolate(&input));
+    }
+}
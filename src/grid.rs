@@ -0,0 +1,294 @@
+use std::{
+    fmt::{self, Display},
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    parse::{lines, Line},
+    ParseError,
+};
+
+/// Position in a [`Grid`], `y` grows downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+}
+
+/// Rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// If the number of cells is not `width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid with one row per line, converting every character with `cell`.
+    /// Characters it rejects are reported as not being `expected`.
+    pub fn parse<R: BufRead>(
+        reader: R,
+        day: u8,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (i, l) in lines(reader, day).enumerate() {
+            let l = l?;
+            let line = Line::new(day, i, &l);
+            for (x, c) in l.char_indices() {
+                cells.push(cell(c).ok_or_else(|| line.error(&l[x..], expected))?);
+            }
+
+            let row_width = l.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width > row_width => {
+                    return Err(line.error_at_end(format!("row of {width} cells")))
+                }
+                Some(width) if width < row_width => {
+                    let end = l.char_indices().nth(width).unwrap().0;
+                    return Err(line.error(&l[end..], "end of row"));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    /// All cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Adjacent point in `direction`, if it is inside the grid.
+    pub fn neighbour(&self, point: Point, direction: Direction) -> Option<Point> {
+        let neighbour = match direction {
+            Direction::Up => Point::new(point.x, point.y.checked_sub(1)?),
+            Direction::Down => Point::new(point.x, point.y + 1),
+            Direction::Left => Point::new(point.x.checked_sub(1)?, point.y),
+            Direction::Right => Point::new(point.x + 1, point.y),
+        };
+        self.contains(neighbour).then_some(neighbour)
+    }
+
+    /// Horizontally and vertically adjacent points inside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.neighbour(point, direction))
+    }
+
+    /// Horizontally, vertically and diagonally adjacent points inside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let neighbour = Point::new(
+                    point.x.checked_add_signed(dx)?,
+                    point.y.checked_add_signed(dy)?,
+                );
+                self.contains(neighbour).then_some(neighbour)
+            })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows with columns.
+    pub fn transpose(&self) -> Self {
+        Grid::new(
+            self.height,
+            self.width,
+            self.columns().flat_map(|column| column.cloned()).collect(),
+        )
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} out of bounds"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(&b"abc\ndef\n"[..], 1, "letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!('f', grid[Point::new(2, 1)]);
+        assert_eq!("abc\ndef\n", grid.to_string());
+
+        let error =
+            Grid::parse(&b"..\n.#"[..], 11, "`.`", |c| (c == '.').then_some(c)).unwrap_err();
+        assert_eq!((2, 2), (error.line, error.column));
+        let error = Grid::parse(&b"...\n.."[..], 11, "`.`", Some).unwrap_err();
+        assert_eq!(
+            (2, 3, "row of 3 cells"),
+            (error.line, error.column, error.expected.as_str())
+        );
+        let error = Grid::parse(&b"..\n..."[..], 11, "`.`", Some).unwrap_err();
+        assert_eq!(
+            (2, 3, "end of row"),
+            (error.line, error.column, error.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = grid();
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!(vec!['b', 'e'], grid.column(1).copied().collect::<Vec<_>>());
+        assert_eq!(3, grid.columns().count());
+        assert_eq!(Some(Point::new(1, 1)), grid.position(|&c| c == 'e'));
+        assert_eq!(None, grid.get(Point::new(3, 0)));
+
+        let transposed = grid.transpose();
+        assert_eq!("ad\nbe\ncf\n", transposed.to_string());
+        assert_eq!(grid, transposed.transpose());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let corner = Point::new(0, 0);
+        assert_eq!(
+            vec![Point::new(0, 1), Point::new(1, 0)],
+            grid.neighbours4(corner).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbours8(corner).count());
+        assert_eq!(5, grid.neighbours8(Point::new(1, 0)).count());
+        assert_eq!(None, grid.neighbour(corner, Direction::Left));
+        assert_eq!(
+            Some(Point::new(2, 1)),
+            grid.neighbour(Point::new(2, 0), Direction::Down)
+        );
+    }
+}
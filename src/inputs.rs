@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+/// Directory holding the puzzle input and examples of a day.
+pub fn dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/day{day}"))
+}
+
+/// The personal puzzle input of a day.
+pub fn puzzle(day: u8) -> PathBuf {
+    dir(day).join("input.txt")
+}
+
+/// The `n`-th example from the puzzle description, numbered from 1.
+pub fn example(day: u8, n: u32) -> PathBuf {
+    dir(day).join(format!("example{n}.txt"))
+}
+
+/// Stable 64-bit FNV-1a hash of an input, to tell apart results computed from different inputs.
+pub fn hash(input: &[u8]) -> u64 {
+    input.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, hash(b""));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, hash(b"a"));
+        assert_eq!(0x8594_4171_f739_67e8, hash(b"foobar"));
+    }
+}
@@ -0,0 +1,14 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod inputs;
+pub mod math;
+pub mod parse;
+pub mod random;
+pub mod readme;
+pub mod scaffold;
+mod solution;
+
+pub use parse::ParseError;
+pub use solution::{run, Part, Run, Solution, SolveError, Solved};
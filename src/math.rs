@@ -0,0 +1,250 @@
+/// Greatest common divisor, `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it does not fit into a `u64`. `lcm(0, n)` is 0.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple, widened so that it can not overflow.
+pub fn lcm_u128(a: u64, b: u64) -> u128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    u128::from(a / gcd(a, b)) * u128::from(b)
+}
+
+/// Least common multiple of all `values`, 1 for none, or `None` on overflow.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g`, where `g` is the non-negative gcd.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Chinese Remainder Theorem over `(residue, modulus)` congruences, the moduli need not be
+/// coprime. Returns the smallest non-negative solution together with the combined modulus, or
+/// `None` if the congruences contradict each other or the combined modulus overflows.
+///
+/// # Panics
+///
+/// If a modulus is not positive.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(r1, m1): (i128, i128), &(r2, m2)| {
+            assert!(m2 > 0, "modulus {m2} is not positive");
+            let r2 = r2.rem_euclid(m2);
+            let (g, p, _) = extended_gcd(m1, m2);
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+
+            // r1 + m1 * k is the solution, where k solves m1 * k ≡ r2 - r1 (mod m2)
+            let step = m2 / g;
+            let k = mul_mod(((r2 - r1) / g).rem_euclid(step), p.rem_euclid(step), step);
+            let modulus = m1.checked_mul(step)?;
+            Some(((r1 + m1 * k).rem_euclid(modulus), modulus))
+        })
+}
+
+/// `a * b % m` for `0 <= a, b < m`, without overflowing.
+fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    // sums of two values below m fit into an u128
+    let (mut a, mut b, m) = (a as u128, b as u128, m as u128);
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a + a) % m;
+        b >>= 1;
+    }
+    result as i128
+}
+
+/// Exact integer square root, the largest `r` with `r * r <= n`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method, starting from a power of two above the root
+    let mut x = 1 << ((128 - n.leading_zeros()).div_ceil(2));
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Binomial coefficient `n` choose `k`, or `None` if it does not fit into a `u128`.
+pub fn binomial(n: u64, k: u64) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    (1..=u128::from(k)).try_fold(1u128, |acc, i| {
+        // acc * factor is divisible by i, reduce first to delay overflows
+        let factor = u128::from(n - k) + i;
+        let g = gcd_u128(acc, i);
+        (acc / g).checked_mul(factor / (i / g))
+    })
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+    use crate::random::Rng;
+
+    fn random_values(count: usize) -> impl Iterator<Item = u64> {
+        let mut rng = Rng::new(0);
+        std::iter::repeat_with(move || rng.next_u64()).take(count)
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(48, 18));
+        assert_eq!(7, gcd(0, 7));
+        assert_eq!(0, gcd(0, 0));
+        assert_eq!(Some(144), lcm(48, 18));
+        assert_eq!(Some(0), lcm(0, 5));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+        assert_eq!(
+            u128::from(u64::MAX) * u128::from(u64::MAX - 1),
+            lcm_u128(u64::MAX, u64::MAX - 1)
+        );
+        assert_eq!(Some(2520), lcm_all(1..=10));
+        assert_eq!(Some(1), lcm_all([]));
+
+        for (a, b) in random_values(2000).tuples() {
+            let (a, b) = (a >> (a % 64), b >> (b % 64));
+            let g = gcd(a, b);
+            assert_eq!(g, gcd(b, a));
+            if g > 0 {
+                assert_eq!((0, 0), (a % g, b % g));
+                assert_eq!(1, gcd(a / g, b / g));
+                assert_eq!(
+                    u128::from(a) * u128::from(b),
+                    u128::from(g) * lcm_u128(a, b)
+                );
+            }
+            assert_eq!(lcm(a, b).map(u128::from), {
+                let wide = lcm_u128(a, b);
+                u64::try_from(wide).ok().map(u128::from)
+            });
+        }
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!((2, -9, 47), extended_gcd(240, 46));
+        for (a, b) in random_values(2000).tuples() {
+            let (a, b) = (i128::from(a as i64), i128::from(b as i64));
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, a * x + b * y);
+            assert_eq!(g as u128, gcd_u128(a.unsigned_abs(), b.unsigned_abs()));
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        // non-coprime moduli
+        assert_eq!(Some((10, 12)), crt(&[(4, 6), (2, 4)]));
+        assert_eq!(None, crt(&[(1, 6), (2, 4)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+        assert_eq!(Some((4, 5)), crt(&[(-1, 5)]));
+        assert_eq!(None, crt(&[(0, i128::MAX), (0, i128::MAX - 1)]));
+
+        for (r1, m1, r2, m2) in random_values(4000).tuples() {
+            let (m1, m2) = (i128::from(m1 % 60 + 1), i128::from(m2 % 60 + 1));
+            let (r1, r2) = (i128::from(r1) % m1, i128::from(r2) % m2);
+            match crt(&[(r1, m1), (r2, m2)]) {
+                Some((x, m)) => {
+                    assert_eq!(m, i128::from(lcm(m1 as u64, m2 as u64).unwrap()));
+                    assert!((0..m).contains(&x));
+                    assert_eq!((r1, r2), (x % m1, x % m2));
+                }
+                None => assert!((0..m1 * m2).all(|x| (x % m1, x % m2) != (r1, r2))),
+            }
+        }
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(
+            vec![0, 1, 1, 1, 2, 2, 2, 2, 2, 3],
+            (0..10).map(isqrt).collect::<Vec<_>>()
+        );
+        assert_eq!(u128::from(u64::MAX), isqrt(u128::MAX));
+        for n in random_values(2000).map(u128::from).chain(0..1000) {
+            for n in [n, n * n, (n * n).saturating_sub(1), n * n + 1] {
+                let r = isqrt(n);
+                let above = (r + 1).checked_mul(r + 1);
+                assert!(
+                    r * r <= n && above.is_none_or(|a| a > n),
+                    "isqrt({n}) = {r}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(Some(1), binomial(0, 0));
+        assert_eq!(Some(0), binomial(3, 4));
+        assert_eq!(Some(10), binomial(5, 2));
+        assert_eq!(
+            Some(100_891_344_545_564_193_334_812_497_256),
+            binomial(100, 50)
+        );
+        assert_eq!(None, binomial(200, 100));
+
+        // Pascal's triangle
+        for n in 1..130 {
+            for k in 1..n {
+                assert_eq!(
+                    binomial(n, k),
+                    binomial(n - 1, k - 1)
+                        .zip(binomial(n - 1, k))
+                        .map(|(a, b)| a + b),
+                    "binomial({n}, {k})"
+                );
+            }
+        }
+    }
+}
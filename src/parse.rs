@@ -0,0 +1,179 @@
+use std::{
+    error::Error,
+    fmt,
+    io::BufRead,
+    str::{self, FromStr},
+};
+
+/// Malformed puzzle input, pointing at the offending place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A single line of input that knows where it came from, so that errors can point into it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    day: u8,
+    number: usize,
+    text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// `index` is 0-based, as given by `enumerate`.
+    pub fn new(day: u8, index: usize, text: &'a str) -> Self {
+        Line {
+            day,
+            number: index + 1,
+            text,
+        }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Creates an error located at the start of `at`, which must be a slice of this line.
+    /// Anything else is reported at the end of the line.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|&offset| offset <= self.text.len() && self.text.is_char_boundary(offset))
+            .unwrap_or(self.text.len());
+
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error located right after the last character of the line.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("`{delimiter}`")))
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Parses every whitespace-separated token of `s`.
+    pub fn parse_all<T: FromStr>(&self, s: &'a str, expected: &str) -> Result<Vec<T>, ParseError> {
+        s.split_whitespace()
+            .map(|token| self.parse(token, expected))
+            .collect()
+    }
+}
+
+/// The lines of `reader`, like [`BufRead::lines`], but a line that can not be read is an error
+/// at that line, and one that is not UTF-8 an error at its first invalid byte.
+pub fn lines<R: BufRead>(reader: R, day: u8) -> impl Iterator<Item = Result<String, ParseError>> {
+    reader.split(b'\n').enumerate().map(move |(i, l)| {
+        let mut l = l.map_err(|e| ParseError {
+            day,
+            line: i + 1,
+            column: 1,
+            expected: format!("a readable line ({e})"),
+        })?;
+        if l.ends_with(b"\r") {
+            l.pop();
+        }
+        String::from_utf8(l).map_err(|e| utf8_error(day, i, e.as_bytes()))
+    })
+}
+
+/// Error at the first byte of `line`, the one at `index`, that is not valid UTF-8.
+pub fn utf8_error(day: u8, index: usize, line: &[u8]) -> ParseError {
+    let valid = match str::from_utf8(line) {
+        Ok(_) => line.len(),
+        Err(e) => e.valid_up_to(),
+    };
+    // the valid prefix, so that the column counts its characters
+    let text = str::from_utf8(&line[..valid]).unwrap_or_default();
+    Line::new(day, index, text).error_at_end("UTF-8 text")
+}
+
+/// Asserts that `S` fails to parse each input with its error, written without the leading day.
+#[cfg(test)]
+pub(crate) fn assert_parse_errors<S: crate::Solution>(cases: &[(&str, &str)]) {
+    for (input, error) in cases {
+        assert_eq!(
+            Err(format!("day {}, {error}", S::DAY)),
+            S::parse(input.as_bytes())
+                .map(|_| ())
+                .map_err(|e| e.to_string()),
+            "{input}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_location() {
+        let text = "Card 1: 41 4x | 83";
+        let line = Line::new(4, 2, text);
+
+        let error = line.parse_all::<i32>(&text[8..13], "number").unwrap_err();
+        assert_eq!(3, error.line);
+        assert_eq!(12, error.column);
+        assert_eq!(
+            "day 4, line 3, column 12: expected number",
+            error.to_string()
+        );
+
+        let error = line.split_once(text, "; ").unwrap_err();
+        assert_eq!(1, error.column);
+        assert_eq!(19, line.error_at_end("`)`").column);
+    }
+
+    #[test]
+    fn test_error_location_counts_chars() {
+        let text = "żółw 12";
+        let line = Line::new(1, 0, text);
+        assert_eq!(6, line.error(&text[text.len() - 2..], "x").column);
+    }
+
+    #[test]
+    fn test_lines_report_unreadable_line() {
+        let input: &[u8] = b"12\r\n\xff3\n45\n\xc5\xbc\xc3\n";
+        let lines = lines(input, 1).collect::<Vec<_>>();
+        assert_eq!(Ok("12".to_string()), lines[0]);
+        assert_eq!(
+            "day 1, line 2, column 1: expected UTF-8 text",
+            lines[1].as_ref().unwrap_err().to_string()
+        );
+        assert_eq!(Ok("45".to_string()), lines[2]);
+        assert_eq!(2, lines[3].as_ref().unwrap_err().column);
+        assert_eq!(4, lines.len());
+    }
+}
@@ -0,0 +1,41 @@
+/// Small deterministic xorshift generator, for generated inputs and property tests that must be
+/// reproducible.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift stays at 0 forever, so the one seed leading there gets another state
+        const MIX: u64 = 0x2545_f491_4f6c_dd1d;
+        match seed ^ MIX {
+            0 => Rng(MIX),
+            state => Rng(state),
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniformly distributed in `0..n`, close enough for any `n` far below `u64::MAX`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_seed_varies() {
+        for seed in [0, 1, 0x2545_f491_4f6c_dd1d] {
+            let mut rng = Rng::new(seed);
+            let values = (0..10).map(|_| rng.next_u64()).collect::<Vec<_>>();
+            assert!(values.iter().all(|&n| n != 0), "seed {seed:#x}");
+        }
+    }
+}
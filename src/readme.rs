@@ -0,0 +1,164 @@
+use std::{collections::BTreeMap, fmt::Write, time::Duration};
+
+use itertools::Itertools;
+
+use crate::{
+    answers::{self, Answer},
+    bench::{Phase, Timing},
+    Part,
+};
+
+pub const PATH: &str = "README.md";
+
+const START: &str =
+    "<!-- progress: generated by `aoc readme` from answers.txt and benchmarks.txt -->";
+const END: &str = "<!-- end of progress -->";
+const HEADER: &str = "| 1-5 | 6-10 | 11-15 | 16-20 | 21-25 |";
+
+/// Stars per day: one for every part with a known answer for the puzzle input.
+pub fn stars(answers: &[Answer]) -> BTreeMap<u8, usize> {
+    answers
+        .iter()
+        .filter(|a| a.input == "input.txt" && a.answer.is_some())
+        .map(|a| (a.day, a.part))
+        .unique()
+        .counts_by(|(day, _)| day)
+        .into_iter()
+        .collect()
+}
+
+/// Renders the star table, with a column per five days, followed by the median runtimes per day.
+pub fn render(stars: &BTreeMap<u8, usize>, timings: &[Timing]) -> String {
+    star_table(stars) + "\n" + &runtime_table(stars, timings)
+}
+
+/// The table with a column per five days, which only depends on the answers.
+pub fn star_table(stars: &BTreeMap<u8, usize>) -> String {
+    let mut table = format!("{HEADER}\n|---|---|---|---|---|\n");
+    for row in 0..5 {
+        table.push('|');
+        for column in 0..5 {
+            let day = column * 5 + row + 1;
+            table += &"⭐".repeat(stars.get(&day).copied().unwrap_or(0));
+            table.push('|');
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// The stars and median runtimes of every day with either.
+fn runtime_table(stars: &BTreeMap<u8, usize>, timings: &[Timing]) -> String {
+    let mut table =
+        "| Day | Stars | Parse | Part 1 | Part 2 |\n|---|---|---|---|---|\n".to_string();
+    let days = stars
+        .iter()
+        .filter(|(_, &stars)| stars > 0)
+        .map(|(&day, _)| day)
+        .chain(timings.iter().map(|t| t.day))
+        .sorted()
+        .dedup();
+    for day in days {
+        let median = |phase| {
+            timings
+                .iter()
+                .find(|t| (t.day, t.phase) == (day, phase))
+                .map_or(String::new(), |t| format_duration(t.median))
+        };
+        writeln!(
+            table,
+            "| {day} | {} | {} | {} | {} |",
+            "⭐".repeat(stars.get(&day).copied().unwrap_or(0)),
+            median(Phase::Parse),
+            median(Phase::Part(Part::One)),
+            median(Phase::Part(Part::Two)),
+        )
+        .unwrap();
+    }
+    table
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+/// Replaces the generated progress section of the README. The first time, the hand-written star
+/// table is replaced instead.
+pub fn update(readme: &str, progress: &str) -> Result<String, String> {
+    let section = format!("{START}\n{progress}{END}\n");
+    if let (Some(start), Some(end)) = (readme.find(START), readme.find(END)) {
+        let end = end + END.len();
+        let end = end + usize::from(readme[end..].starts_with('\n'));
+        return Ok(format!("{}{section}{}", &readme[..start], &readme[end..]));
+    }
+
+    let start = readme
+        .find(HEADER)
+        .ok_or(format!("{PATH}: star table not found"))?;
+    let end = readme[start..]
+        .split_inclusive('\n')
+        .take_while(|line| line.starts_with('|'))
+        .map(str::len)
+        .sum::<usize>()
+        + start;
+    Ok(format!("{}{section}{}", &readme[..start], &readme[end..]))
+}
+
+/// Regenerates the progress section from `registry`, the content of answers.txt, and the
+/// `timings` of benchmarks.txt.
+pub fn regenerate(readme: &str, registry: &str, timings: &[Timing]) -> Result<String, String> {
+    update(readme, &render(&stars(&answers::parse(registry)?), timings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stars() {
+        let answers = answers::parse(
+            "1 input.txt 1 1\n1 input.txt 2 2\n1 example1.txt 2 3\n2 input.txt 1 4\n2 input.txt 2 ?\n",
+        )
+        .unwrap();
+        assert_eq!(BTreeMap::from([(1, 2), (2, 1)]), stars(&answers));
+    }
+
+    #[test]
+    fn test_render() {
+        let timing = Timing {
+            day: 12,
+            phase: Phase::Part(Part::One),
+            iterations: 1,
+            min: Duration::from_millis(2),
+            median: Duration::from_millis(2),
+            max: Duration::from_millis(2),
+        };
+        let progress = render(&BTreeMap::from([(1, 2), (12, 1), (13, 0)]), &[timing]);
+        let lines = progress.lines().collect_vec();
+
+        assert_eq!("|⭐⭐|||||", lines[2]);
+        assert_eq!("|||⭐|||", lines[3]);
+        assert_eq!("| 1 | ⭐⭐ |  |  |  |", lines[10]);
+        assert_eq!("| 12 | ⭐ |  | 2.00ms |  |", lines[11]);
+        assert_eq!(12, lines.len());
+    }
+
+    #[test]
+    fn test_update() {
+        let readme = "# AoC\n\n| 1-5 | 6-10 | 11-15 | 16-20 | 21-25 |\n|---|---|---|---|---|\n\
+            |⭐⭐|||||\n||||||\n||||||\n||||||\n||||||\n\n## Usage\n";
+
+        let updated = update(readme, "table\n").unwrap();
+        assert_eq!(
+            format!("# AoC\n\n{START}\ntable\n{END}\n\n## Usage\n"),
+            updated
+        );
+        let updated = update(&updated, "new table\n").unwrap();
+        assert_eq!(
+            format!("# AoC\n\n{START}\nnew table\n{END}\n\n## Usage\n"),
+            updated
+        );
+
+        assert!(update("# AoC\n", "table\n").is_err());
+    }
+}
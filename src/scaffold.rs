@@ -0,0 +1,217 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+use crate::{answers, bench, inputs, readme};
+
+/// Module registering every day with the runner.
+pub const DAYS_PATH: &str = "src/days/mod.rs";
+
+pub fn day_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("src/days/day{day}.rs"))
+}
+
+/// Source of a new day, implementing [`Solution`](crate::Solution) with parts that fail as not
+/// solved yet.
+pub fn template(day: u8) -> String {
+    format!(
+        "\
+use std::io::BufRead;
+
+use crate::{{parse::lines, ParseError, SolveError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError> {{
+        read_input(reader)
+    }}
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError> {{
+        part1(input)
+    }}
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError> {{
+        part2(input)
+    }}
+}}
+
+fn part1(_input: &[String]) -> Result<i64, SolveError> {{
+    Err(SolveError::new(Day{day}::DAY, \"part 1 is not solved yet\"))
+}}
+
+fn part2(_input: &[String]) -> Result<i64, SolveError> {{
+    Err(SolveError::new(Day{day}::DAY, \"part 2 is not solved yet\"))
+}}
+
+fn read_input<R: BufRead>(reader: R) -> Result<Vec<String>, ParseError> {{
+    lines(reader, Day{day}::DAY).collect()
+}}
+"
+    )
+}
+
+/// Adds the module of `day` and its entry in `DAYS`, keeping both sorted like rustfmt does.
+pub fn register_day(days_mod: &str, day: u8) -> Result<String, String> {
+    let module = format!("pub mod day{day};");
+    let entry = format!("    day::<day{day}::Day{day}>(),");
+    let mut lines = days_mod.lines().map(str::to_string).collect_vec();
+    if lines.contains(&module) {
+        return Err(format!("day {day} is already registered"));
+    }
+
+    let modules = lines
+        .iter()
+        .positions(|line| line.starts_with("pub mod day"))
+        .collect_vec();
+    let last = *modules
+        .last()
+        .ok_or(format!("{DAYS_PATH}: no day modules found"))?;
+    let name = format!("day{day}");
+    let at = modules
+        .iter()
+        .copied()
+        .find(|&i| lines[i]["pub mod ".len()..].trim_end_matches(';') > name.as_str())
+        .unwrap_or(last + 1);
+    lines.insert(at, module);
+
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))
+        .ok_or(format!("{DAYS_PATH}: `DAYS` not found"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or(format!("{DAYS_PATH}: end of `DAYS` not found"))?;
+    let number = |line: &str| {
+        line.trim()
+            .strip_prefix("day::<day")
+            .and_then(|rest| rest.split_once("::"))
+            .and_then(|(number, _)| number.parse::<u8>().ok())
+    };
+    let at = (start + 1..end)
+        .find(|&i| number(&lines[i]).is_some_and(|n| n > day))
+        .unwrap_or(end);
+    lines.insert(at, entry);
+
+    let mut days_mod = lines.join("\n");
+    days_mod.push('\n');
+    Ok(days_mod)
+}
+
+/// Appends placeholder entries for both parts of the input and the first example of `day`.
+pub fn register_answers(registry: &str, day: u8) -> String {
+    let mut registry = registry.to_string();
+    if !registry.is_empty() && !registry.ends_with('\n') {
+        registry.push('\n');
+    }
+    for input in ["input.txt", "example1.txt"] {
+        for part in 1..=2 {
+            registry += &format!("{day:<6} {input:<13} {part:<5} ?\n");
+        }
+    }
+    registry
+}
+
+/// Creates and registers everything needed to solve `day`, returning the touched files.
+pub fn new_day(day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {day} is not an Advent of Code day"));
+    }
+    let source = day_path(day);
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+
+    let days_mod = register_day(&read(DAYS_PATH)?, day)?;
+    let registry = register_answers(&read(answers::PATH)?, day);
+    let timings = bench::load_existing(bench::PATH)?;
+    let readme = readme::regenerate(&read(readme::PATH)?, &registry, &timings)?;
+
+    let dir = inputs::dir(day);
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    let mut touched = Vec::new();
+    for (path, content) in [
+        (source, template(day)),
+        (PathBuf::from(DAYS_PATH), days_mod),
+        (PathBuf::from(answers::PATH), registry),
+        (PathBuf::from(readme::PATH), readme),
+        (inputs::puzzle(day), String::new()),
+        (inputs::example(day, 1), String::new()),
+    ] {
+        if path.starts_with(&dir) && path.exists() {
+            continue;
+        }
+        fs::write(&path, content).map_err(|e| format!("{}: {e}", path.display()))?;
+        touched.push(path);
+    }
+    Ok(touched)
+}
+
+fn read(path: impl AsRef<Path>) -> Result<String, String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_day() {
+        let days_mod = "use crate::Solution;\n\npub mod day1;\npub mod day2;\n\n\
+            pub const DAYS: &[Day] = &[\n    day::<day1::Day1>(),\n    day::<day2::Day2>(),\n];\n";
+
+        let updated = register_day(days_mod, 10).unwrap();
+        assert_eq!(
+            "use crate::Solution;\n\npub mod day1;\npub mod day10;\npub mod day2;\n\n\
+            pub const DAYS: &[Day] = &[\n    day::<day1::Day1>(),\n    day::<day2::Day2>(),\n    \
+            day::<day10::Day10>(),\n];\n",
+            updated
+        );
+        assert!(register_day(&updated, 10).is_err());
+        let updated = register_day(&updated, 13).unwrap();
+        assert!(updated.contains("pub mod day10;\npub mod day13;\npub mod day2;\n"));
+        assert!(register_day("", 10).is_err());
+    }
+
+    #[test]
+    fn test_template() {
+        let source = template(13);
+        assert!(source.contains("impl Solution for Day13 {"));
+        assert!(source.contains("Err(SolveError::new(Day13::DAY, \"part 2 is not solved yet\"))"));
+        assert!(!source.contains("unimplemented!"));
+    }
+
+    #[test]
+    fn test_register_answers() {
+        let registry = register_answers("# header\n1      input.txt     1     42", 13);
+        let answers = answers::parse(&registry).unwrap();
+        assert_eq!(5, answers.len());
+        assert!(answers[1..]
+            .iter()
+            .all(|a| a.day == 13 && a.answer.is_none()));
+    }
+
+    #[test]
+    fn test_new_day_range() {
+        // rejected before anything is read or written
+        for day in [0, 26] {
+            assert_eq!(
+                Err(format!("day {day} is not an Advent of Code day")),
+                new_day(day)
+            );
+        }
+        assert!(!day_path(26).exists());
+    }
+}
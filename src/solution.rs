@@ -0,0 +1,132 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::BufRead,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::ParseError;
+
+/// One half of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{s}`, expected 1 or 2")),
+        }
+    }
+}
+
+/// Common shape of every day: parse the input once, then answer both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, ParseError>;
+
+    /// Fails on input that parses but has no answer. A day 1 line spelling out its numbers in
+    /// words is fine for part 2, but has no digit for part 1.
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, SolveError>;
+}
+
+/// Well-formed input that a part has no answer for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: u8,
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(day: u8, reason: impl Into<String>) -> Self {
+        SolveError {
+            day,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}: {}", self.day, self.reason)
+    }
+}
+
+impl Error for SolveError {}
+
+/// Answer of one part rendered as a string, or why it has none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Result<String, SolveError>,
+    pub duration: Duration,
+}
+
+/// Outcome of running a day over one input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse: Duration,
+    pub parts: Vec<Solved>,
+}
+
+/// Parses the input with `S` and solves the requested parts, timing each phase separately. Only
+/// parsing fails as a whole, a part failing leaves the others to be solved.
+pub fn run<S: Solution>(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(reader)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&input).map(|answer| answer.to_string()),
+                Part::Two => S::part2(&input).map(|answer| answer.to_string()),
+            };
+            Solved {
+                part,
+                answer,
+                duration: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run { parse, parts })
+}
@@ -0,0 +1,38 @@
+use advent_of_rust_2023::answers::{self, Answer};
+
+fn check(answers: &[Answer]) {
+    let failures = answers::verify(answers);
+    for failure in &failures {
+        eprintln!("{failure}");
+    }
+    assert!(failures.is_empty(), "{} answers are wrong", failures.len());
+}
+
+#[test]
+fn test_answers() {
+    let answers = answers::load(answers::PATH).unwrap();
+    check(&answers.into_iter().filter(|a| !a.slow).collect::<Vec<_>>());
+}
+
+#[test]
+#[ignore = "too slow for the CI worker, run in release mode"]
+fn test_slow_answers() {
+    let answers = answers::load(answers::PATH).unwrap();
+    check(&answers.into_iter().filter(|a| a.slow).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_parse() {
+    let answers =
+        answers::parse("# comment\n\n3 input.txt 2 42\n5 input.txt 2 1 slow\n6 input.txt 1 ?\n")
+            .unwrap();
+    assert_eq!(3, answers.len());
+    assert_eq!("day 3, input.txt, part 2", answers[0].to_string());
+    assert_eq!(Some("42"), answers[0].answer.as_deref());
+    assert!(!answers[0].slow && answers[1].slow);
+    assert_eq!(None, answers[2].answer);
+
+    assert!(answers::parse("3 input.txt 3 42").is_err());
+    assert!(answers::parse("3 input.txt 2 42 fast").is_err());
+    assert!(answers::parse("3 input.txt 2").is_err());
+}
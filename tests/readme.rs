@@ -0,0 +1,16 @@
+use std::fs;
+
+use advent_of_rust_2023::{answers, readme};
+
+// only the star table, the runtimes change with every `aoc bench`
+#[test]
+fn test_readme_stars_are_up_to_date() {
+    let current = fs::read_to_string(readme::PATH).unwrap();
+    let registry = fs::read_to_string(answers::PATH).unwrap();
+    let stars = readme::stars(&answers::parse(&registry).unwrap());
+    assert!(
+        current.contains(&readme::star_table(&stars)),
+        "{} is outdated, run `cargo run --bin aoc -- readme`",
+        readme::PATH
+    );
+}